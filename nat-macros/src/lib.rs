@@ -1,49 +1,247 @@
 use syn::{
-    LitInt, Token, Type, parenthesized, parse::Parse, parse_macro_input, punctuated::Punctuated,
-    token::Paren,
+    Ident, LitInt, Token, Type, parenthesized, parse::Parse, parse_macro_input,
+    punctuated::Punctuated, spanned::Spanned, token::Paren,
 };
 
-use quote::quote;
+use proc_macro_crate::FoundCrate;
+use quote::{ToTokens, quote, quote_spanned};
+
+fn crate_path() -> proc_macro2::TokenStream {
+    let found = proc_macro_crate::crate_name("tlist")
+        .or_else(|_| proc_macro_crate::crate_name("tlist-nightly"));
+
+    match found {
+        Ok(FoundCrate::Itself) => quote! { crate },
+        Ok(FoundCrate::Name(name)) => {
+            let ident = Ident::new(&name, proc_macro2::Span::call_site());
+            quote! { ::#ident }
+        }
+        Err(_) => quote! { ::tlist },
+    }
+}
+
+fn parse_literal(lit: &LitInt) -> syn::Result<u64> {
+    let max = match lit.suffix() {
+        "" | "u64" | "u128" | "usize" | "i128" => u64::MAX,
+        "u8" => u8::MAX.into(),
+        "u16" => u16::MAX.into(),
+        "u32" => u32::MAX.into(),
+        "i8" => i8::MAX as u64,
+        "i16" => i16::MAX as u64,
+        "i32" => i32::MAX as u64,
+        "i64" | "isize" => i64::MAX as u64,
+        suffix => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("unsupported integer suffix `{suffix}`"),
+            ));
+        }
+    };
+
+    let n = lit.base10_parse::<u64>()?;
+
+    if n > max {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!("literal out of range for `{}`", lit.suffix()),
+        ));
+    }
+
+    Ok(n)
+}
+
+fn eval_const(expr: &syn::Expr) -> syn::Result<u64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => parse_literal(lit),
+        syn::Expr::Paren(paren) => eval_const(&paren.expr),
+        syn::Expr::Group(group) => eval_const(&group.expr),
+        syn::Expr::Binary(binary) => {
+            let lhs = eval_const(&binary.left)?;
+            let rhs = eval_const(&binary.right)?;
+
+            let result = match binary.op {
+                syn::BinOp::Add(_) => lhs.checked_add(rhs),
+                syn::BinOp::Sub(_) => lhs.checked_sub(rhs),
+                syn::BinOp::Mul(_) => lhs.checked_mul(rhs),
+                syn::BinOp::Div(_) | syn::BinOp::Rem(_) if rhs == 0 => {
+                    return Err(syn::Error::new_spanned(
+                        &binary.right,
+                        "attempt to divide by zero",
+                    ));
+                }
+                syn::BinOp::Div(_) => lhs.checked_div(rhs),
+                syn::BinOp::Rem(_) => lhs.checked_rem(rhs),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        binary.op,
+                        "unsupported operator, expected one of `+`, `-`, `*`, `/` or `%`",
+                    ));
+                }
+            };
+
+            result.ok_or_else(|| {
+                let message = match binary.op {
+                    syn::BinOp::Sub(_) => "attempt to subtract with overflow",
+                    _ => "attempt to compute a natural number larger than `u64::MAX`",
+                };
+                syn::Error::new_spanned(binary, message)
+            })
+        }
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "expected a constant natural number expression",
+        )),
+    }
+}
 
 #[proc_macro]
 pub fn nat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Parse the input tokens into a syntax tree
-    let input = parse_macro_input!(input as LitInt);
+    let input = parse_macro_input!(input as syn::Expr);
 
-    // Extract the value of the input integer
-    let n = input.base10_parse::<u64>().unwrap();
+    // Evaluate the input expression
+    let n = match eval_const(&input) {
+        Ok(n) => n,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    nat_tokens(n, &crate_path()).into()
+}
 
-    let mut result = quote! { tlist::Zero };
+fn nat_tokens(n: u64, krate: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut result = quote! { #krate::Zero };
 
     for _ in 0..n {
-        result = quote! { tlist::Succ<#result> };
+        result = quote! { #krate::Succ<#result> };
     }
 
-    result.into()
+    result
 }
 
-struct ExprAdd {
+fn bin_nat_tokens(n: u64, krate: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut result = quote! { #krate::UTerm };
+
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        result = if n >> bit & 1 == 0 {
+            quote! { #krate::B0<#result> }
+        } else {
+            quote! { #krate::B1<#result> }
+        };
+    }
+
+    result
+}
+
+#[proc_macro]
+pub fn bin_nat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::Expr);
+
+    match eval_const(&input) {
+        Ok(n) => bin_nat_tokens(n, &crate_path()).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct ForEachBinNat {
+    callback: Ident,
     #[allow(dead_code)]
+    comma: Token![,],
+    max: LitInt,
+}
+
+impl Parse for ForEachBinNat {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            callback: input.parse()?,
+            comma: input.parse()?,
+            max: input.parse()?,
+        })
+    }
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn for_each_bin_nat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ForEachBinNat { callback, max, .. } = parse_macro_input!(input as ForEachBinNat);
+
+    let max = match max.base10_parse::<u64>() {
+        Ok(max) => max,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let krate = crate_path();
+    let invocations = (0..=max).map(|n| {
+        let literal = proc_macro2::Literal::u64_unsuffixed(n);
+        let bin = bin_nat_tokens(n, &krate);
+        quote! { #callback!(#literal, [#bin]); }
+    });
+
+    quote! { #(#invocations)* }.into()
+}
+
+struct ExprAdd {
     add: Token![+],
     exprs: Punctuated<Expr, Token![,]>,
 }
 
+struct ExprSub {
+    sub: Token![-],
+    exprs: Punctuated<Expr, Token![,]>,
+}
+
 struct ExprMul {
-    #[allow(dead_code)]
     mul: Token![*],
     exprs: Punctuated<Expr, Token![,]>,
 }
 
+struct ExprDiv {
+    div: Token![/],
+    exprs: Punctuated<Expr, Token![,]>,
+}
+
+struct ExprRem {
+    rem: Token![%],
+    exprs: Punctuated<Expr, Token![,]>,
+}
+
+struct ExprPow {
+    pow: Token![^],
+    exprs: Punctuated<Expr, Token![,]>,
+}
+
 struct ExprParen {
-    #[allow(dead_code)]
     paren: Paren,
     expr: Box<Expr>,
 }
 
+enum BinOp {
+    Add(Token![+]),
+    Sub(Token![-]),
+    Mul(Token![*]),
+    Div(Token![/]),
+    Rem(Token![%]),
+    Pow(Token![^]),
+}
+
+struct ExprBinary {
+    left: Box<Expr>,
+    op: BinOp,
+    right: Box<Expr>,
+}
+
 enum Expr {
     Type(Type),
+    Lit(LitInt),
+    Binary(ExprBinary),
     Add(ExprAdd),
+    Sub(ExprSub),
     Mul(ExprMul),
+    Div(ExprDiv),
+    Rem(ExprRem),
+    Pow(ExprPow),
     Paren(ExprParen),
 }
 
@@ -56,6 +254,15 @@ impl Parse for ExprAdd {
     }
 }
 
+impl Parse for ExprSub {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            sub: input.parse()?,
+            exprs: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
 impl Parse for ExprMul {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         Ok(Self {
@@ -65,6 +272,33 @@ impl Parse for ExprMul {
     }
 }
 
+impl Parse for ExprDiv {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            div: input.parse()?,
+            exprs: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+impl Parse for ExprRem {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            rem: input.parse()?,
+            exprs: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+impl Parse for ExprPow {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            pow: input.parse()?,
+            exprs: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
 impl Parse for ExprParen {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let content;
@@ -79,61 +313,272 @@ impl Parse for Expr {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.peek(Token![+]) {
             Ok(Self::Add(input.parse()?))
+        } else if input.peek(Token![-]) {
+            Ok(Self::Sub(input.parse()?))
         } else if input.peek(Token![*]) {
             Ok(Self::Mul(input.parse()?))
-        } else if input.peek(Paren) {
-            Ok(Self::Paren(input.parse()?))
+        } else if input.peek(Token![/]) {
+            Ok(Self::Div(input.parse()?))
+        } else if input.peek(Token![%]) {
+            Ok(Self::Rem(input.parse()?))
+        } else if input.peek(Token![^]) {
+            Ok(Self::Pow(input.parse()?))
         } else {
-            Ok(Self::Type(input.parse()?))
+            parse_binary(input, 0)
         }
     }
 }
 
+impl BinOp {
+    fn peek(input: syn::parse::ParseStream) -> Option<u8> {
+        if input.peek(Token![+]) || input.peek(Token![-]) {
+            Some(0)
+        } else if input.peek(Token![*]) || input.peek(Token![/]) || input.peek(Token![%]) {
+            Some(1)
+        } else if input.peek(Token![^]) {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    fn is_right_assoc(&self) -> bool {
+        matches!(self, Self::Pow(_))
+    }
+
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::Add(add) => add.span,
+            Self::Sub(sub) => sub.span,
+            Self::Mul(mul) => mul.span,
+            Self::Div(div) => div.span,
+            Self::Rem(rem) => rem.span,
+            Self::Pow(pow) => pow.span,
+        }
+    }
+}
+
+impl Parse for BinOp {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(Token![+]) {
+            Ok(Self::Add(input.parse()?))
+        } else if lookahead.peek(Token![-]) {
+            Ok(Self::Sub(input.parse()?))
+        } else if lookahead.peek(Token![*]) {
+            Ok(Self::Mul(input.parse()?))
+        } else if lookahead.peek(Token![/]) {
+            Ok(Self::Div(input.parse()?))
+        } else if lookahead.peek(Token![%]) {
+            Ok(Self::Rem(input.parse()?))
+        } else if lookahead.peek(Token![^]) {
+            Ok(Self::Pow(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+fn parse_operand(input: syn::parse::ParseStream) -> syn::Result<Expr> {
+    if input.peek(Paren) {
+        Ok(Expr::Paren(input.parse()?))
+    } else if input.peek(LitInt) {
+        Ok(Expr::Lit(input.parse()?))
+    } else {
+        Ok(Expr::Type(Type::without_plus(input)?))
+    }
+}
+
+// Precedence climbing: `+ -` < `* / %` < `^`, where `^` is right associative
+fn parse_binary(input: syn::parse::ParseStream, min_prec: u8) -> syn::Result<Expr> {
+    let mut left = parse_operand(input)?;
+
+    while let Some(prec) = BinOp::peek(input) {
+        if prec < min_prec {
+            break;
+        }
+
+        let op: BinOp = input.parse()?;
+        let next_prec = if op.is_right_assoc() { prec } else { prec + 1 };
+        let right = parse_binary(input, next_prec)?;
+
+        left = Expr::Binary(ExprBinary {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+        });
+    }
+
+    Ok(left)
+}
+
 trait Eval {
-    fn eval(&self) -> Result<proc_macro2::TokenStream, syn::Error>;
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error>;
 }
 
 impl Eval for ExprAdd {
-    fn eval(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
         let evals: Vec<_> = self
             .exprs
             .iter()
-            .map(|expr| expr.eval())
+            .map(|expr| expr.eval(krate))
             .collect::<Result<_, _>>()?;
 
-        Ok(evals.into_iter().fold(quote! { tlist::Zero }, |acc, eval| {
-            quote! { <#acc as tlist::Nat>::Add<#eval> }
-        }))
+        Ok(evals
+            .into_iter()
+            .fold(quote! { #krate::Zero }, |acc, eval| {
+                quote! { <#acc as #krate::Nat>::Add<#eval> }
+            }))
+    }
+}
+
+fn eval_left_fold(
+    op: impl ToTokens,
+    exprs: &Punctuated<Expr, Token![,]>,
+    krate: &proc_macro2::TokenStream,
+    f: impl Fn(proc_macro2::TokenStream, proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let mut evals = exprs.iter().map(|expr| expr.eval(krate));
+
+    let first = evals
+        .next()
+        .ok_or_else(|| syn::Error::new_spanned(op, "expected at least one operand"))??;
+
+    evals.try_fold(first, |acc, eval| Ok(f(acc, eval?)))
+}
+
+impl Eval for ExprSub {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        eval_left_fold(self.sub, &self.exprs, krate, |acc, eval| {
+            quote! { <#acc as #krate::Sub<#eval>>::Output }
+        })
     }
 }
 
 impl Eval for ExprMul {
-    fn eval(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
         let evals: Vec<_> = self
             .exprs
             .iter()
-            .map(|expr| expr.eval())
+            .map(|expr| expr.eval(krate))
             .collect::<Result<_, _>>()?;
 
-        Ok(evals.into_iter().fold(quote! { tlist::Succ<tlist::Zero> }, |acc, eval| {
-            quote! { <#acc as tlist::Nat>::Mul<#eval> }
-        }))
+        Ok(evals
+            .into_iter()
+            .fold(quote! { #krate::Succ<#krate::Zero> }, |acc, eval| {
+                quote! { <#acc as #krate::Nat>::Mul<#eval> }
+            }))
+    }
+}
+
+impl Eval for ExprDiv {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        eval_left_fold(self.div, &self.exprs, krate, |acc, eval| {
+            quote! { <#acc as #krate::Div<#eval>>::Output }
+        })
+    }
+}
+
+impl Eval for ExprRem {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        eval_left_fold(self.rem, &self.exprs, krate, |acc, eval| {
+            quote! { <#acc as #krate::Rem<#eval>>::Output }
+        })
+    }
+}
+
+impl Eval for ExprPow {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        eval_left_fold(self.pow, &self.exprs, krate, |acc, eval| {
+            quote! { <#acc as #krate::Pow<#eval>>::Output }
+        })
     }
 }
 
 impl Eval for ExprParen {
-    fn eval(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
-        self.expr.eval()
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        self.expr.eval(krate)
+    }
+}
+
+impl Eval for ExprBinary {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        let left = self.left.eval(krate)?;
+        let right = self.right.eval(krate)?;
+        let span = self.right.span();
+
+        Ok(match self.op {
+            BinOp::Add(_) => quote_spanned! {span=> <#left as #krate::Nat>::Add<#right> },
+            BinOp::Sub(_) => quote_spanned! {span=> <#left as #krate::Sub<#right>>::Output },
+            BinOp::Mul(_) => quote_spanned! {span=> <#left as #krate::Nat>::Mul<#right> },
+            BinOp::Div(_) => quote_spanned! {span=> <#left as #krate::Div<#right>>::Output },
+            BinOp::Rem(_) => quote_spanned! {span=> <#left as #krate::Rem<#right>>::Output },
+            BinOp::Pow(_) => quote_spanned! {span=> <#left as #krate::Pow<#right>>::Output },
+        })
+    }
+}
+
+impl Expr {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::Type(ty) => ty.span(),
+            Self::Lit(lit) => lit.span(),
+            Self::Binary(binary) => binary.op.span(),
+            Self::Add(add) => add.add.span,
+            Self::Sub(sub) => sub.sub.span,
+            Self::Mul(mul) => mul.mul.span,
+            Self::Div(div) => div.div.span,
+            Self::Rem(rem) => rem.rem.span,
+            Self::Pow(pow) => pow.pow.span,
+            Self::Paren(paren) => paren.paren.span.join(),
+        }
     }
 }
 
 impl Eval for Expr {
-    fn eval(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
+    fn eval(
+        &self,
+        krate: &proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
         match self {
             Self::Type(ty) => Ok(quote! { #ty }),
-            Self::Add(add) => add.eval(),
-            Self::Mul(mul) => mul.eval(),
-            Self::Paren(paren) => paren.eval(),
+            Self::Lit(lit) => Ok(nat_tokens(parse_literal(lit)?, krate)),
+            Self::Binary(binary) => binary.eval(krate),
+            Self::Add(add) => add.eval(krate),
+            Self::Sub(sub) => sub.eval(krate),
+            Self::Mul(mul) => mul.eval(krate),
+            Self::Div(div) => div.eval(krate),
+            Self::Rem(rem) => rem.eval(krate),
+            Self::Pow(pow) => pow.eval(krate),
+            Self::Paren(paren) => paren.eval(krate),
         }
     }
 }
@@ -142,7 +587,7 @@ impl Eval for Expr {
 pub fn expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as Expr);
 
-    let result = input.eval();
+    let result = input.eval(&crate_path());
     match result {
         Ok(result) => result.into(),
         Err(err) => err.to_compile_error().into(),
@@ -2,27 +2,6 @@
 
 pub use tlist::*;
 
-pub trait TBool {
-    const VALUE: bool;
-
-    type Or<T: TBool>: TBool;
-}
-
-pub struct TTrue;
-pub struct TFalse;
-
-impl TBool for TTrue {
-    const VALUE: bool = true;
-
-    type Or<T: TBool> = TTrue;
-}
-
-impl TBool for TFalse {
-    const VALUE: bool = false;
-
-    type Or<T: TBool> = T;
-}
-
 pub trait IsEqual<T> {
     type Output: TBool;
 }
@@ -51,6 +30,14 @@ where
     type Output = <Head::Output as TBool>::Or<Tail::Output>;
 }
 
+impl<T, List, Tail> Contains<T> for Nested<List, Tail>
+where
+    List: IsEqual<T>,
+    Tail: Contains<T>,
+{
+    type Output = <List::Output as TBool>::Or<Tail::Output>;
+}
+
 pub trait Map<F> {
     type Output;
     
@@ -77,6 +64,18 @@ where
     }
 }
 
+impl<F, T, List, Tail> Map<F> for Nested<List, Tail>
+where
+    F: FnMut(List) -> T,
+    Tail: Map<F>,
+{
+    type Output = Cons<T, Tail::Output>;
+    
+    fn map(self, mut f: F) -> Self::Output {
+        Cons::new(f(self.0), self.1.map(f))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tlist::list;
@@ -2,6 +2,7 @@ use std::{any::Any, marker::PhantomData};
 
 use replace_with::replace_with_and_return;
 
+pub use nat_macros::{bin_nat, expr, nat};
 pub use tlist_macros::*;
 
 // Natural Numbers
@@ -10,6 +11,9 @@ pub trait Nat {
 
     type Add<T: Nat>: Nat;
     type Mul<T: Nat>: Nat;
+
+    type Peano: Nat;
+    type Binary: Bin;
 }
 
 #[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
@@ -20,8 +24,11 @@ pub struct Succ<N: Nat>(PhantomData<N>);
 impl Nat for Zero {
     const VALUE: usize = 0;
 
-    type Add<T: Nat> = T;
+    type Add<T: Nat> = T::Peano;
     type Mul<T: Nat> = Zero;
+
+    type Peano = Zero;
+    type Binary = UTerm;
 }
 
 impl<N: Nat> Nat for Succ<N> {
@@ -29,692 +36,3424 @@ impl<N: Nat> Nat for Succ<N> {
 
     type Add<T: Nat> = Succ<N::Add<T>>;
     type Mul<T: Nat> = <N::Mul<T> as Nat>::Add<T>;
-}
-
-// Optional
-pub trait Optional<T> {
-    type AsRef<'a>: Optional<&'a T>
-    where
-        T: 'a;
-    type AsMut<'a>: Optional<&'a mut T>
-    where
-        T: 'a;
 
-    fn to_option(self) -> Option<T>;
+    type Peano = Succ<N::Peano>;
+    type Binary = <N::Binary as private::BinOps>::Inc;
 }
 
+// Binary natural numbers
+pub trait Bin: Nat + private::BinOps {}
+
 #[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-pub struct TNone;
+pub struct UTerm;
 #[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-pub struct TSome<T>(T);
-
-impl<T> Optional<T> for TNone {
-    type AsRef<'a>
-        = TNone
-    where
-        T: 'a;
-    type AsMut<'a>
-        = TNone
-    where
-        T: 'a;
+pub struct B0<N: Bin>(PhantomData<N>);
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct B1<N: Bin>(PhantomData<N>);
 
-    fn to_option(self) -> Option<T> {
-        None
-    }
-}
+impl Nat for UTerm {
+    const VALUE: usize = 0;
 
-impl<T> Optional<T> for TSome<T> {
-    type AsRef<'a>
-        = TSome<&'a T>
-    where
-        T: 'a;
-    type AsMut<'a>
-        = TSome<&'a mut T>
-    where
-        T: 'a;
+    type Add<T: Nat> = T::Binary;
+    type Mul<T: Nat> = UTerm;
 
-    fn to_option(self) -> Option<T> {
-        Some(self.0)
-    }
+    type Peano = Zero;
+    type Binary = UTerm;
 }
 
-// Type-level list
-pub trait HList {
-    fn get<N>(&self) -> &<Self as Get<N>>::Output
-    where
-        Self: Get<N>,
-    {
-        Get::get(self)
-    }
-
-    fn get_mut<N>(&mut self) -> &mut <Self as Get<N>>::Output
-    where
-        Self: GetMut<N>,
-    {
-        GetMut::get_mut(self)
-    }
-
-    fn get_optional<N>(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_>
-    where
-        Self: GetOptional<N>,
-    {
-        GetOptional::get_optional(self)
-    }
+impl<N: Bin> Nat for B0<N> {
+    const VALUE: usize = N::VALUE * 2;
 
-    fn get_optional_mut<N>(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_>
-    where
-        Self: GetOptionalMut<N>,
-    {
-        GetOptionalMut::get_optional_mut(self)
-    }
+    type Add<T: Nat> = <Self as private::BinOps>::AddBin<T::Binary>;
+    type Mul<T: Nat> = <Self as private::BinOps>::MulBin<T::Binary>;
 
-    fn push<T>(self, value: T) -> Cons<T, Self>
-    where
-        Self: Sized,
-    {
-        Cons(value, self)
-    }
+    type Peano = <N::Peano as Nat>::Add<N::Peano>;
+    type Binary = Self;
+}
 
-    fn pop(self) -> (Self::Removed, Self::Output)
-    where
-        Self: Remove<Zero> + Sized,
-    {
-        Remove::remove(self)
-    }
+impl<N: Bin> Nat for B1<N> {
+    const VALUE: usize = N::VALUE * 2 + 1;
 
-    fn insert<N, T>(self, value: T) -> Self::Output
-    where
-        Self: Insert<N, T> + Sized,
-    {
-        Insert::insert(self, value)
-    }
+    type Add<T: Nat> = <Self as private::BinOps>::AddBin<T::Binary>;
+    type Mul<T: Nat> = <Self as private::BinOps>::MulBin<T::Binary>;
 
-    fn remove<N>(self) -> (Self::Removed, Self::Output)
-    where
-        Self: Remove<N> + Sized,
-    {
-        Remove::remove(self)
-    }
+    type Peano = Succ<<N::Peano as Nat>::Add<N::Peano>>;
+    type Binary = Self;
+}
 
-    fn remove_optional<N>(self) -> (Self::Optional, Self::Output)
-    where
-        Self: RemoveOptional<N> + Sized,
-    {
-        RemoveOptional::remove_optional(self)
-    }
+impl Bin for UTerm {}
+impl<N: Bin> Bin for B0<N> {}
+impl<N: Bin> Bin for B1<N> {}
 
-    fn iter<'a>(&'a self) -> <&'a Self as IntoIterator>::IntoIter
-    where
-        &'a Self: IntoIterator,
-    {
-        self.into_iter()
-    }
+#[diagnostic::on_unimplemented(
+    message = "cannot subtract `{Rhs}` from `{Self}`",
+    note = "the result would be less than `Zero`"
+)]
+pub trait Sub<Rhs: Nat>: Nat {
+    type Output: Nat;
+}
 
-    fn iter_mut<'a>(&'a mut self) -> <&'a mut Self as IntoIterator>::IntoIter
-    where
-        &'a mut Self: IntoIterator,
-    {
-        self.into_iter()
-    }
+impl<N: Nat> Sub<Zero> for N {
+    type Output = N;
 }
 
-#[macro_export]
-macro_rules! list {
-    () => {
-        $crate::Nil
-    };
-    ($head:expr) => {
-        $crate::Cons::new($head, $crate::Nil)
-    };
-    ($head:expr, $($tail:expr),+ $(,)?) => {
-        $crate::Cons::new($head, $crate::list!($($tail),+))
-    };
+impl<N: Nat, M: Nat> Sub<Succ<M>> for Succ<N>
+where
+    N: Sub<M>,
+{
+    type Output = <N as Sub<M>>::Output;
 }
 
-#[macro_export]
-macro_rules! List {
-    () => {
-        $crate::Nil
-    };
-    ($head:ty) => {
-        $crate::Cons<$head, $crate::Nil>
-    };
-    ($head:ty, $($tail:ty),+ $(,)?) => {
-        $crate::Cons<$head, $crate::List!($($tail),+)>
-    };
+pub trait CheckedSub<Rhs: Nat>: Nat {
+    type Output;
 }
 
-#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub struct Nil;
-#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub struct Cons<Head, Tail>(pub Head, pub Tail);
+impl<N: Nat> CheckedSub<Zero> for N {
+    type Output = TSome<N>;
+}
 
-impl Nil {}
-impl<Head, Tail> Cons<Head, Tail> {}
+impl<M: Nat> CheckedSub<Succ<M>> for Zero {
+    type Output = TNone;
+}
 
-impl HList for Nil {}
-impl<Head, Tail: HList> HList for Cons<Head, Tail> {}
+impl<N: Nat, M: Nat> CheckedSub<Succ<M>> for Succ<N>
+where
+    N: CheckedSub<M>,
+{
+    type Output = <N as CheckedSub<M>>::Output;
+}
 
-impl<Head, Tail> Cons<Head, Tail> {
-    pub fn new(head: Head, tail: Tail) -> Self {
-        Cons(head, tail)
-    }
+pub trait SaturatingSub<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-pub trait Get<N> {
-    type Output;
+impl<N: Nat> SaturatingSub<Zero> for N {
+    type Output = N;
+}
 
-    fn get(&self) -> &Self::Output;
+impl<M: Nat> SaturatingSub<Succ<M>> for Zero {
+    type Output = Zero;
 }
 
-impl<Head, Tail> Get<Zero> for Cons<Head, Tail> {
-    type Output = Head;
+impl<N: Nat, M: Nat> SaturatingSub<Succ<M>> for Succ<N>
+where
+    N: SaturatingSub<M>,
+{
+    type Output = <N as SaturatingSub<M>>::Output;
+}
 
-    fn get(&self) -> &Self::Output {
-        &self.0
-    }
+#[diagnostic::on_unimplemented(
+    message = "cannot divide `{Self}` by `{Rhs}`",
+    note = "division by `Zero` is not defined"
+)]
+pub trait DivRem<Rhs: Nat>: Nat {
+    type Quotient: Nat;
+    type Remainder: Nat;
 }
 
-impl<N: Nat, Head, Tail> Get<Succ<N>> for Cons<Head, Tail>
+impl<N: Nat, M: Nat> DivRem<Succ<M>> for N
 where
-    Tail: Get<N>,
+    N: CheckedSub<Succ<M>>,
+    <N as CheckedSub<Succ<M>>>::Output: private::DivRemStep<N, Succ<M>>,
 {
-    type Output = <Tail as Get<N>>::Output;
+    type Quotient =
+        <<N as CheckedSub<Succ<M>>>::Output as private::DivRemStep<N, Succ<M>>>::Quotient;
+    type Remainder =
+        <<N as CheckedSub<Succ<M>>>::Output as private::DivRemStep<N, Succ<M>>>::Remainder;
+}
 
-    fn get(&self) -> &Self::Output {
-        self.1.get()
-    }
+#[diagnostic::on_unimplemented(
+    message = "cannot divide `{Self}` by `{Rhs}`",
+    note = "division by `Zero` is not defined"
+)]
+pub trait Div<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-pub trait GetMut<N>: Get<N> {
-    fn get_mut(&mut self) -> &mut Self::Output;
+impl<N: Nat, D: Nat> Div<D> for N
+where
+    N: DivRem<D>,
+{
+    type Output = <N as DivRem<D>>::Quotient;
 }
 
-impl<Head, Tail> GetMut<Zero> for Cons<Head, Tail> {
-    fn get_mut(&mut self) -> &mut Self::Output {
-        &mut self.0
-    }
+#[diagnostic::on_unimplemented(
+    message = "cannot compute the remainder of `{Self}` divided by `{Rhs}`",
+    note = "division by `Zero` is not defined"
+)]
+pub trait Rem<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-impl<N: Nat, Head, Tail> GetMut<Succ<N>> for Cons<Head, Tail>
+impl<N: Nat, D: Nat> Rem<D> for N
 where
-    Tail: GetMut<N>,
+    N: DivRem<D>,
 {
-    fn get_mut(&mut self) -> &mut Self::Output {
-        self.1.get_mut()
-    }
+    type Output = <N as DivRem<D>>::Remainder;
 }
 
-pub trait GetOptional<N> {
-    type Output;
-    type Optional: Optional<Self::Output>;
-
-    fn get_optional(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_>;
+pub trait Gcd<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-pub trait GetOptionalMut<N>: GetOptional<N> {
-    fn get_optional_mut(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_>;
+impl<N: Nat> Gcd<Zero> for N {
+    type Output = N;
 }
 
-impl<N> GetOptional<N> for Nil {
-    type Output = ();
-    type Optional = TNone;
+impl<N: Nat, M: Nat> Gcd<Succ<M>> for N
+where
+    N: Rem<Succ<M>>,
+    Succ<M>: Gcd<<N as Rem<Succ<M>>>::Output>,
+{
+    type Output = <Succ<M> as Gcd<<N as Rem<Succ<M>>>::Output>>::Output;
+}
 
-    fn get_optional(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
-        TNone
-    }
+pub trait Lcm<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-impl<N> GetOptionalMut<N> for Nil {
-    fn get_optional_mut(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
-        TNone
-    }
+impl<M: Nat> Lcm<M> for Zero {
+    type Output = Zero;
 }
 
-impl<Head, Tail> GetOptional<Zero> for Cons<Head, Tail> {
-    type Output = Head;
-    type Optional = TSome<Head>;
+impl<N: Nat, M: Nat> Lcm<M> for Succ<N>
+where
+    Succ<N>: Gcd<M>,
+    M: Div<<Succ<N> as Gcd<M>>::Output>,
+{
+    type Output = <Succ<N> as Nat>::Mul<<M as Div<<Succ<N> as Gcd<M>>::Output>>::Output>;
+}
 
-    fn get_optional(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
-        TSome(&self.0)
-    }
+pub trait Pow<Exp: Nat>: Nat {
+    type Output: Nat;
 }
 
-impl<Head, Tail> GetOptionalMut<Zero> for Cons<Head, Tail> {
-    fn get_optional_mut(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
-        TSome(&mut self.0)
-    }
+impl<N: Nat> Pow<Zero> for N {
+    type Output = Succ<Zero>;
 }
 
-impl<N: Nat, Head, Tail> GetOptional<Succ<N>> for Cons<Head, Tail>
+impl<N: Nat, E: Nat> Pow<Succ<E>> for N
 where
-    Tail: GetOptional<N>,
+    N: Pow<E>,
 {
-    type Output = <Tail as GetOptional<N>>::Output;
-    type Optional = <Tail as GetOptional<N>>::Optional;
+    type Output = <<N as Pow<E>>::Output as Nat>::Mul<N>;
+}
 
-    fn get_optional(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
-        self.1.get_optional()
-    }
+#[diagnostic::on_unimplemented(
+    message = "cannot compute the logarithm of `{Self}`",
+    note = "the logarithm of `Zero` is not defined"
+)]
+pub trait Log2: Nat {
+    type Output: Nat;
 }
 
-impl<N: Nat, Head, Tail> GetOptionalMut<Succ<N>> for Cons<Head, Tail>
+impl<N: Nat> Log2 for Succ<N>
 where
-    Tail: GetOptionalMut<N>,
+    Succ<N>: Div<Succ<Succ<Zero>>>,
+    <Succ<N> as Div<Succ<Succ<Zero>>>>::Output: private::Log2Step,
 {
-    fn get_optional_mut(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
-        self.1.get_optional_mut()
-    }
+    type Output = <<Succ<N> as Div<Succ<Succ<Zero>>>>::Output as private::Log2Step>::Output;
 }
 
-pub trait Insert<N, T> {
-    type Output;
+pub trait Sqrt: Nat {
+    type Output: Nat;
+}
 
-    fn insert(self, value: T) -> Self::Output;
+impl<N: Nat> Sqrt for N
+where
+    N: private::SqrtFrom<Zero, Succ<Zero>>,
+{
+    type Output = <N as private::SqrtFrom<Zero, Succ<Zero>>>::Output;
 }
 
-impl<L, T> Insert<Zero, T> for L {
-    type Output = Cons<T, L>;
+pub trait TOrdering {
+    const VALUE: std::cmp::Ordering;
+}
 
-    fn insert(self, value: T) -> Self::Output {
-        Cons(value, self)
-    }
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Less;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Equal;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Greater;
+
+impl TOrdering for Less {
+    const VALUE: std::cmp::Ordering = std::cmp::Ordering::Less;
 }
 
-impl<N: Nat, Head, Tail, T> Insert<Succ<N>, T> for Cons<Head, Tail>
-where
-    Tail: Insert<N, T>,
-{
-    type Output = Cons<Head, <Tail as Insert<N, T>>::Output>;
+impl TOrdering for Equal {
+    const VALUE: std::cmp::Ordering = std::cmp::Ordering::Equal;
+}
 
-    fn insert(self, value: T) -> Self::Output {
-        Cons(self.0, self.1.insert(value))
-    }
+impl TOrdering for Greater {
+    const VALUE: std::cmp::Ordering = std::cmp::Ordering::Greater;
 }
 
-pub trait Remove<N> {
-    type Removed;
-    type Output;
+pub trait Cmp<Rhs: Nat>: Nat {
+    type Output: TOrdering;
+}
 
-    fn remove(self) -> (Self::Removed, Self::Output);
+impl Cmp<Zero> for Zero {
+    type Output = Equal;
 }
 
-impl<Head, Tail> Remove<Zero> for Cons<Head, Tail> {
-    type Removed = Head;
-    type Output = Tail;
+impl<M: Nat> Cmp<Succ<M>> for Zero {
+    type Output = Less;
+}
 
-    fn remove(self) -> (Self::Removed, Self::Output) {
-        (self.0, self.1)
-    }
+impl<N: Nat> Cmp<Zero> for Succ<N> {
+    type Output = Greater;
 }
 
-impl<N: Nat, Head, Tail> Remove<Succ<N>> for Cons<Head, Tail>
+impl<N: Nat, M: Nat> Cmp<Succ<M>> for Succ<N>
 where
-    Tail: Remove<N>,
+    N: Cmp<M>,
 {
-    type Removed = <Tail as Remove<N>>::Removed;
-    type Output = Cons<Head, <Tail as Remove<N>>::Output>;
+    type Output = <N as Cmp<M>>::Output;
+}
 
-    fn remove(self) -> (Self::Removed, Self::Output) {
-        let (removed, tail) = self.1.remove();
-        (removed, Cons(self.0, tail))
-    }
+pub trait Min<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-pub trait RemoveOptional<N> {
-    type Removed;
-    type Output;
-    type Optional: Optional<Self::Removed>;
+impl<N: Nat> Min<Zero> for N {
+    type Output = Zero;
+}
 
-    fn remove_optional(self) -> (Self::Optional, Self::Output);
+impl<M: Nat> Min<Succ<M>> for Zero {
+    type Output = Zero;
 }
 
-impl<N> RemoveOptional<N> for Nil {
-    type Removed = ();
-    type Output = Nil;
-    type Optional = TNone;
+impl<N: Nat, M: Nat> Min<Succ<M>> for Succ<N>
+where
+    N: Min<M>,
+{
+    type Output = Succ<<N as Min<M>>::Output>;
+}
 
-    fn remove_optional(self) -> (Self::Optional, Self::Output) {
-        (TNone, Nil)
-    }
+pub trait Max<Rhs: Nat>: Nat {
+    type Output: Nat;
 }
 
-impl<Head, Tail> RemoveOptional<Zero> for Cons<Head, Tail> {
-    type Removed = Head;
-    type Output = Tail;
-    type Optional = TSome<Head>;
+impl<N: Nat> Max<Zero> for N {
+    type Output = N;
+}
 
-    fn remove_optional(self) -> (Self::Optional, Self::Output) {
-        (TSome(self.0), self.1)
-    }
+impl<M: Nat> Max<Succ<M>> for Zero {
+    type Output = Succ<M>;
 }
 
-impl<N, Head, Tail> RemoveOptional<Succ<N>> for Cons<Head, Tail>
+impl<N: Nat, M: Nat> Max<Succ<M>> for Succ<N>
 where
-    N: Nat,
-    Tail: RemoveOptional<N>,
+    N: Max<M>,
 {
-    type Removed = <Tail as RemoveOptional<N>>::Removed;
-    type Output = Cons<Head, <Tail as RemoveOptional<N>>::Output>;
-    type Optional = <Tail as RemoveOptional<N>>::Optional;
-
-    fn remove_optional(self) -> (Self::Optional, Self::Output) {
-        let (removed, tail) = self.1.remove_optional();
-        (removed, Cons(self.0, tail))
-    }
+    type Output = Succ<<N as Max<M>>::Output>;
 }
 
-pub trait All<T> {}
+#[diagnostic::on_unimplemented(message = "`{Self}` is not less than `{Rhs}`")]
+pub trait IsLess<Rhs: Nat>: Nat {}
 
-impl<T> All<T> for Nil {}
-impl<T, Tail> All<T> for Cons<T, Tail> where Tail: All<T> {}
+impl<M: Nat> IsLess<Succ<M>> for Zero {}
+impl<N: Nat, M: Nat> IsLess<Succ<M>> for Succ<N> where N: IsLess<M> {}
 
-trait PopOptional<T> {
-    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>);
-    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>);
-    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>);
-}
+#[diagnostic::on_unimplemented(message = "`{Self}` is not less than or equal to `{Rhs}`")]
+pub trait IsLessOrEqual<Rhs: Nat>: Nat {}
 
-impl<T> PopOptional<T> for Nil {
-    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>) {
-        (None, Box::new(Nil))
-    }
+impl<M: Nat> IsLessOrEqual<M> for Zero {}
+impl<N: Nat, M: Nat> IsLessOrEqual<Succ<M>> for Succ<N> where N: IsLessOrEqual<M> {}
 
-    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>) {
-        (None, self)
-    }
+// Const generics
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct ConstNat<const N: usize>;
 
-    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>) {
-        (None, self)
-    }
+pub trait ToArray: Nat {
+    type Array<T>;
 }
 
-impl<T, Tail> PopOptional<T> for Cons<T, Tail>
+impl ToArray for Zero {
+    type Array<T> = [T; 0];
+}
+
+impl<N: Nat> ToArray for Succ<N>
 where
-    Tail: PopOptional<T> + 'static,
+    <Succ<N> as Nat>::Binary: ToArray,
 {
-    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>) {
-        let Cons(value, tail) = *self;
-        (Some(value), Box::new(tail))
-    }
+    type Array<T> = <<Succ<N> as Nat>::Binary as ToArray>::Array<T>;
+}
 
-    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>) {
-        (Some(&self.0), &self.1)
-    }
+macro_rules! impl_const_nat {
+    ($n:literal, [$($bin:tt)*]) => {
+        impl ToNat for ConstNat<$n> {
+            type Output = $($bin)*;
+        }
 
-    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>) {
-        (Some(&mut self.0), &mut self.1)
-    }
+        impl ToArray for $($bin)* {
+            type Array<T> = [T; $n];
+        }
+    };
 }
 
-pub struct IntoIter<T> {
-    list: Box<dyn PopOptional<T>>,
+nat_macros::for_each_bin_nat!(impl_const_nat, 1024);
+
+// Integers
+pub trait Int {
+    const VALUE: isize;
+
+    type Abs: Int;
 }
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct ZeroInt;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct PosInt<N: Nat>(PhantomData<N>);
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct NegInt<N: Nat>(PhantomData<N>);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        replace_with_and_return(
-            &mut self.list,
-            || Box::new(Nil),
-            PopOptional::pop_optional_box,
-        )
-    }
+impl Int for ZeroInt {
+    const VALUE: isize = 0;
+
+    type Abs = ZeroInt;
 }
 
-pub struct Iter<'a, T> {
-    list: &'a dyn PopOptional<T>,
+impl<N: Nat> Int for PosInt<Succ<N>> {
+    const VALUE: isize = Succ::<N>::VALUE as isize;
+
+    type Abs = Self;
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+impl<N: Nat> Int for NegInt<Succ<N>> {
+    const VALUE: isize = -(Succ::<N>::VALUE as isize);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let (value, list) = self.list.pop_optional_ref();
-        self.list = list;
-        value
-    }
+    type Abs = PosInt<Succ<N>>;
 }
 
-pub struct IterMut<'a, T> {
-    list: &'a mut dyn PopOptional<T>,
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be converted to a `Nat`")]
+pub trait ToNat {
+    type Output: Nat;
 }
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+impl ToNat for ZeroInt {
+    type Output = Zero;
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        replace_with_and_return(
-            &mut self.list,
-            || Box::leak(Box::new(Nil)),
-            PopOptional::pop_optional_mut,
-        )
-    }
+impl<N: Nat> ToNat for PosInt<Succ<N>> {
+    type Output = Succ<N>;
 }
 
-impl IntoIterator for Nil {
-    type Item = ();
-    type IntoIter = IntoIter<()>;
+impl std::ops::Neg for ZeroInt {
+    type Output = ZeroInt;
 
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter {
-            list: Box::new(self),
-        }
+    fn neg(self) -> Self::Output {
+        ZeroInt
     }
 }
 
-impl<Head, Tail> IntoIterator for Cons<Head, Tail>
-where
-    Head: 'static,
-    Tail: PopOptional<Head> + 'static,
-{
-    type Item = Head;
-    type IntoIter = IntoIter<Head>;
+impl<N: Nat> std::ops::Neg for PosInt<Succ<N>> {
+    type Output = NegInt<Succ<N>>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter {
-            list: Box::new(self),
-        }
+    fn neg(self) -> Self::Output {
+        NegInt(PhantomData)
     }
 }
 
-impl<'a> IntoIterator for &'a Nil {
-    type Item = &'a ();
-    type IntoIter = Iter<'a, ()>;
+impl<N: Nat> std::ops::Neg for NegInt<Succ<N>> {
+    type Output = PosInt<Succ<N>>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        Iter { list: self }
+    fn neg(self) -> Self::Output {
+        PosInt(PhantomData)
     }
 }
 
-impl<'a, Head, Tail> IntoIterator for &'a Cons<Head, Tail>
-where
-    Head: 'a,
-    Tail: PopOptional<Head> + 'static,
-{
-    type Item = &'a Head;
-    type IntoIter = Iter<'a, Head>;
+impl<I: Int> std::ops::Add<I> for ZeroInt {
+    type Output = I;
 
-    fn into_iter(self) -> Self::IntoIter {
-        Iter { list: self }
+    fn add(self, rhs: I) -> Self::Output {
+        rhs
     }
 }
 
-impl<'a> IntoIterator for &'a mut Nil {
-    type Item = &'a mut ();
-    type IntoIter = IterMut<'a, ()>;
+impl<N: Nat> std::ops::Add<ZeroInt> for PosInt<Succ<N>> {
+    type Output = Self;
 
-    fn into_iter(self) -> Self::IntoIter {
-        IterMut { list: self }
+    fn add(self, _: ZeroInt) -> Self::Output {
+        self
     }
 }
 
-impl<'a, Head, Tail> IntoIterator for &'a mut Cons<Head, Tail>
-where
-    Head: 'a,
-    Tail: PopOptional<Head> + 'static,
-{
-    type Item = &'a mut Head;
-    type IntoIter = IterMut<'a, Head>;
+impl<N: Nat> std::ops::Add<ZeroInt> for NegInt<Succ<N>> {
+    type Output = Self;
 
-    fn into_iter(self) -> Self::IntoIter {
-        IterMut { list: self }
+    fn add(self, _: ZeroInt) -> Self::Output {
+        self
     }
 }
 
-pub trait AsRefList<'a> {
-    type Output;
+impl<N: Nat, M: Nat> std::ops::Add<PosInt<Succ<M>>> for PosInt<Succ<N>> {
+    type Output = PosInt<Succ<N::Add<Succ<M>>>>;
 
-    fn as_ref_list(&'a self) -> Self::Output;
+    fn add(self, _: PosInt<Succ<M>>) -> Self::Output {
+        PosInt(PhantomData)
+    }
 }
 
-pub trait AsMutList<'a> {
-    type Output;
+impl<N: Nat, M: Nat> std::ops::Add<NegInt<Succ<M>>> for NegInt<Succ<N>> {
+    type Output = NegInt<Succ<N::Add<Succ<M>>>>;
 
-    fn as_mut_list(&'a mut self) -> Self::Output;
+    fn add(self, _: NegInt<Succ<M>>) -> Self::Output {
+        NegInt(PhantomData)
+    }
 }
 
-impl<'a> AsRefList<'a> for Nil {
-    type Output = Nil;
+impl<N: Nat, M: Nat> std::ops::Add<NegInt<Succ<M>>> for PosInt<Succ<N>>
+where
+    N: Cmp<M>,
+    <N as Cmp<M>>::Output: private::SignedSub<N, M>,
+{
+    type Output = <<N as Cmp<M>>::Output as private::SignedSub<N, M>>::Output;
 
-    fn as_ref_list(&'a self) -> Self::Output {
-        Nil
+    fn add(self, _: NegInt<Succ<M>>) -> Self::Output {
+        <<N as Cmp<M>>::Output as private::SignedSub<N, M>>::signed_sub()
     }
 }
 
-impl<'a, Head, Tail> AsRefList<'a> for Cons<Head, Tail>
+impl<N: Nat, M: Nat> std::ops::Add<PosInt<Succ<M>>> for NegInt<Succ<N>>
 where
-    Head: 'a,
-    Tail: AsRefList<'a>,
+    M: Cmp<N>,
+    <M as Cmp<N>>::Output: private::SignedSub<M, N>,
 {
-    type Output = Cons<&'a Head, <Tail as AsRefList<'a>>::Output>;
+    type Output = <<M as Cmp<N>>::Output as private::SignedSub<M, N>>::Output;
 
-    fn as_ref_list(&'a self) -> Self::Output {
-        Cons(&self.0, self.1.as_ref_list())
+    fn add(self, _: PosInt<Succ<M>>) -> Self::Output {
+        <<M as Cmp<N>>::Output as private::SignedSub<M, N>>::signed_sub()
     }
 }
 
-impl<'a> AsMutList<'a> for Nil {
-    type Output = Nil;
+impl<I> std::ops::Sub<I> for ZeroInt
+where
+    I: std::ops::Neg,
+    ZeroInt: std::ops::Add<I::Output>,
+{
+    type Output = <ZeroInt as std::ops::Add<I::Output>>::Output;
 
-    fn as_mut_list(&'a mut self) -> Self::Output {
-        Nil
+    fn sub(self, rhs: I) -> Self::Output {
+        self + -rhs
     }
 }
 
-impl<'a, Head, Tail> AsMutList<'a> for Cons<Head, Tail>
+impl<N: Nat, I> std::ops::Sub<I> for PosInt<Succ<N>>
 where
-    Head: 'a,
-    Tail: AsMutList<'a>,
+    I: std::ops::Neg,
+    PosInt<Succ<N>>: std::ops::Add<I::Output>,
 {
-    type Output = Cons<&'a mut Head, <Tail as AsMutList<'a>>::Output>;
+    type Output = <PosInt<Succ<N>> as std::ops::Add<I::Output>>::Output;
 
-    fn as_mut_list(&'a mut self) -> Self::Output {
-        Cons(&mut self.0, self.1.as_mut_list())
+    fn sub(self, rhs: I) -> Self::Output {
+        self + -rhs
     }
 }
 
-pub trait AsDynRef<'a> {
-    type Output: All<&'a dyn Any>;
+impl<N: Nat, I> std::ops::Sub<I> for NegInt<Succ<N>>
+where
+    I: std::ops::Neg,
+    NegInt<Succ<N>>: std::ops::Add<I::Output>,
+{
+    type Output = <NegInt<Succ<N>> as std::ops::Add<I::Output>>::Output;
 
-    fn as_dyn_ref(&'a self) -> Self::Output;
+    fn sub(self, rhs: I) -> Self::Output {
+        self + -rhs
+    }
 }
 
-pub trait AsDynMut<'a> {
-    type Output: All<&'a mut dyn Any>;
+impl<I: Int> std::ops::Mul<I> for ZeroInt {
+    type Output = ZeroInt;
 
-    fn as_dyn_mut(&'a mut self) -> Self::Output;
+    fn mul(self, _: I) -> Self::Output {
+        ZeroInt
+    }
 }
 
-pub trait ToDynBox {
-    type Output: All<Box<dyn Any>>;
+impl<N: Nat> std::ops::Mul<ZeroInt> for PosInt<Succ<N>> {
+    type Output = ZeroInt;
 
-    fn to_dyn_box(self) -> Self::Output;
+    fn mul(self, _: ZeroInt) -> Self::Output {
+        ZeroInt
+    }
 }
 
-impl ToDynBox for Nil {
-    type Output = Nil;
+impl<N: Nat> std::ops::Mul<ZeroInt> for NegInt<Succ<N>> {
+    type Output = ZeroInt;
 
-    fn to_dyn_box(self) -> Self::Output {
-        Nil
+    fn mul(self, _: ZeroInt) -> Self::Output {
+        ZeroInt
     }
 }
 
-impl<Head, Tail> ToDynBox for Cons<Head, Tail>
-where
-    Head: 'static,
-    Tail: ToDynBox,
-{
-    type Output = Cons<Box<dyn Any>, <Tail as ToDynBox>::Output>;
+impl<N: Nat, M: Nat> std::ops::Mul<PosInt<Succ<M>>> for PosInt<Succ<N>> {
+    type Output = PosInt<<Succ<N> as Nat>::Mul<Succ<M>>>;
 
-    fn to_dyn_box(self) -> Self::Output {
-        Cons(Box::new(self.0), self.1.to_dyn_box())
+    fn mul(self, _: PosInt<Succ<M>>) -> Self::Output {
+        PosInt(PhantomData)
     }
 }
 
-impl<'a> AsDynRef<'a> for Nil {
-    type Output = Nil;
+impl<N: Nat, M: Nat> std::ops::Mul<NegInt<Succ<M>>> for PosInt<Succ<N>> {
+    type Output = NegInt<<Succ<N> as Nat>::Mul<Succ<M>>>;
 
-    fn as_dyn_ref(&'a self) -> Self::Output {
-        Nil
+    fn mul(self, _: NegInt<Succ<M>>) -> Self::Output {
+        NegInt(PhantomData)
     }
 }
 
-impl<'a, Head, Tail> AsDynRef<'a> for Cons<Head, Tail>
-where
-    Head: 'static,
-    Tail: AsDynRef<'a>,
-{
-    type Output = Cons<&'a dyn Any, <Tail as AsDynRef<'a>>::Output>;
+impl<N: Nat, M: Nat> std::ops::Mul<PosInt<Succ<M>>> for NegInt<Succ<N>> {
+    type Output = NegInt<<Succ<N> as Nat>::Mul<Succ<M>>>;
 
-    fn as_dyn_ref(&'a self) -> Self::Output {
-        Cons(&self.0, self.1.as_dyn_ref())
+    fn mul(self, _: PosInt<Succ<M>>) -> Self::Output {
+        NegInt(PhantomData)
     }
 }
 
-impl<'a> AsDynMut<'a> for Nil {
-    type Output = Nil;
+impl<N: Nat, M: Nat> std::ops::Mul<NegInt<Succ<M>>> for NegInt<Succ<N>> {
+    type Output = PosInt<<Succ<N> as Nat>::Mul<Succ<M>>>;
 
-    fn as_dyn_mut(&'a mut self) -> Self::Output {
-        Nil
+    fn mul(self, _: NegInt<Succ<M>>) -> Self::Output {
+        PosInt(PhantomData)
     }
 }
 
-impl<'a, Head, Tail> AsDynMut<'a> for Cons<Head, Tail>
-where
-    Head: 'static,
-    Tail: AsDynMut<'a>,
-{
-    type Output = Cons<&'a mut dyn Any, <Tail as AsDynMut<'a>>::Output>;
+// Booleans
+pub trait TBool {
+    const VALUE: bool;
 
-    fn as_dyn_mut(&'a mut self) -> Self::Output {
-        Cons(&mut self.0, self.1.as_dyn_mut())
-    }
+    type Not: TBool;
+    type Or<T: TBool>: TBool;
+    type And<T: TBool>: TBool;
+    type Xor<T: TBool>: TBool;
+    type Implies<T: TBool>: TBool;
+    type If<Then, Else>;
+
+    fn select<Then, Else>(then: Then, otherwise: Else) -> Self::If<Then, Else>;
 }
 
-pub trait AllInto<T> {
-    type Into: All<T>;
+pub type If<Cond, Then, Else> = <Cond as TBool>::If<Then, Else>;
 
-    fn into(self) -> Self::Into;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct TTrue;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct TFalse;
+
+impl TBool for TTrue {
+    const VALUE: bool = true;
+
+    type Not = TFalse;
+    type Or<T: TBool> = TTrue;
+    type And<T: TBool> = T;
+    type Xor<T: TBool> = T::Not;
+    type Implies<T: TBool> = T;
+    type If<Then, Else> = Then;
+
+    fn select<Then, Else>(then: Then, _: Else) -> Self::If<Then, Else> {
+        then
+    }
 }
 
-impl<T> AllInto<T> for Nil {
-    type Into = Nil;
+impl TBool for TFalse {
+    const VALUE: bool = false;
 
-    fn into(self) -> Self::Into {
-        Nil
+    type Not = TTrue;
+    type Or<T: TBool> = T;
+    type And<T: TBool> = TFalse;
+    type Xor<T: TBool> = T;
+    type Implies<T: TBool> = TTrue;
+    type If<Then, Else> = Else;
+
+    fn select<Then, Else>(_: Then, otherwise: Else) -> Self::If<Then, Else> {
+        otherwise
     }
 }
 
-impl<Head, Tail, T> AllInto<T> for Cons<Head, Tail>
-where
-    Head: Into<T>,
-    Tail: AllInto<T>,
-{
-    type Into = Cons<T, <Tail as AllInto<T>>::Into>;
+// Optional
+pub trait Optional<T>: private::XorSome<T> {
+    const IS_SOME: bool;
 
-    fn into(self) -> Self::Into {
+    type IsSome: TBool;
+
+    type AsRef<'a>: Optional<&'a T>
+    where
+        Self: 'a,
+        T: 'a;
+    type AsMut<'a>: Optional<&'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+    type Map<U>: Optional<U>;
+    type AndThen<U, O: Optional<U>>: Optional<U>;
+    type Or<O: Optional<T>>: Optional<T>;
+    type And<U, O: Optional<U>>: Optional<U>;
+    type Xor<O: Optional<T>>: Optional<T>;
+    type Flatten<U>: Optional<U>
+    where
+        T: Optional<U>;
+    type Zip<U, O: Optional<U>>: Optional<(T, U)>;
+
+    fn to_option(self) -> Option<T>;
+
+    fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
+    where
+        T: 'a;
+
+    fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
+    where
+        T: 'a;
+
+    fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Self::Map<U>;
+
+    fn and_then<U, O: Optional<U>, F: FnOnce(T) -> O>(self, f: F) -> Self::AndThen<U, O>;
+
+    fn or<O: Optional<T>>(self, other: O) -> Self::Or<O>;
+
+    fn and<U, O: Optional<U>>(self, other: O) -> Self::And<U, O>;
+
+    fn xor<O: Optional<T>>(self, other: O) -> Self::Xor<O>;
+
+    fn flatten<U>(self) -> Self::Flatten<U>
+    where
+        T: Optional<U>;
+
+    fn zip<U, O: Optional<U>>(self, other: O) -> Self::Zip<U, O>;
+
+    fn unwrap_or(self, default: T) -> T;
+}
+
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct TNone;
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct TSome<T>(pub T);
+
+impl<T> TSome<T> {
+    pub fn new(value: T) -> Self {
+        TSome(value)
+    }
+
+    pub fn unwrap(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Optional<T> for TNone {
+    const IS_SOME: bool = false;
+
+    type IsSome = TFalse;
+
+    type AsRef<'a>
+        = TNone
+    where
+        Self: 'a,
+        T: 'a;
+    type AsMut<'a>
+        = TNone
+    where
+        Self: 'a,
+        T: 'a;
+    type Map<U> = TNone;
+    type AndThen<U, O: Optional<U>> = TNone;
+    type Or<O: Optional<T>> = O;
+    type And<U, O: Optional<U>> = TNone;
+    type Xor<O: Optional<T>> = O;
+    type Flatten<U>
+        = TNone
+    where
+        T: Optional<U>;
+    type Zip<U, O: Optional<U>> = TNone;
+
+    fn to_option(self) -> Option<T> {
+        None
+    }
+
+    fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
+    where
+        T: 'a,
+    {
+        TNone
+    }
+
+    fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
+    where
+        T: 'a,
+    {
+        TNone
+    }
+
+    fn map<U, F: FnOnce(T) -> U>(self, _: F) -> Self::Map<U> {
+        TNone
+    }
+
+    fn and_then<U, O: Optional<U>, F: FnOnce(T) -> O>(self, _: F) -> Self::AndThen<U, O> {
+        TNone
+    }
+
+    fn or<O: Optional<T>>(self, other: O) -> Self::Or<O> {
+        other
+    }
+
+    fn and<U, O: Optional<U>>(self, _: O) -> Self::And<U, O> {
+        TNone
+    }
+
+    fn xor<O: Optional<T>>(self, other: O) -> Self::Xor<O> {
+        other
+    }
+
+    fn flatten<U>(self) -> Self::Flatten<U>
+    where
+        T: Optional<U>,
+    {
+        TNone
+    }
+
+    fn zip<U, O: Optional<U>>(self, _: O) -> Self::Zip<U, O> {
+        TNone
+    }
+
+    fn unwrap_or(self, default: T) -> T {
+        default
+    }
+}
+
+impl<T> Optional<T> for TSome<T> {
+    const IS_SOME: bool = true;
+
+    type IsSome = TTrue;
+
+    type AsRef<'a>
+        = TSome<&'a T>
+    where
+        Self: 'a,
+        T: 'a;
+    type AsMut<'a>
+        = TSome<&'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+    type Map<U> = TSome<U>;
+    type AndThen<U, O: Optional<U>> = O;
+    type Or<O: Optional<T>> = Self;
+    type And<U, O: Optional<U>> = O;
+    type Xor<O: Optional<T>> = <O as private::XorSome<T>>::Output;
+    type Flatten<U>
+        = T
+    where
+        T: Optional<U>;
+    type Zip<U, O: Optional<U>> = O::Map<(T, U)>;
+
+    fn to_option(self) -> Option<T> {
+        Some(self.0)
+    }
+
+    fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
+    where
+        T: 'a,
+    {
+        TSome(&self.0)
+    }
+
+    fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
+    where
+        T: 'a,
+    {
+        TSome(&mut self.0)
+    }
+
+    fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Self::Map<U> {
+        TSome(f(self.0))
+    }
+
+    fn and_then<U, O: Optional<U>, F: FnOnce(T) -> O>(self, f: F) -> Self::AndThen<U, O> {
+        f(self.0)
+    }
+
+    fn or<O: Optional<T>>(self, _: O) -> Self::Or<O> {
+        self
+    }
+
+    fn and<U, O: Optional<U>>(self, other: O) -> Self::And<U, O> {
+        other
+    }
+
+    fn xor<O: Optional<T>>(self, other: O) -> Self::Xor<O> {
+        other.xor_some(self.0)
+    }
+
+    fn flatten<U>(self) -> Self::Flatten<U>
+    where
+        T: Optional<U>,
+    {
+        self.0
+    }
+
+    fn zip<U, O: Optional<U>>(self, other: O) -> Self::Zip<U, O> {
+        other.map(|value| (self.0, value))
+    }
+
+    fn unwrap_or(self, _: T) -> T {
+        self.0
+    }
+}
+
+// Type-level list
+pub trait HList {
+    fn len(&self) -> usize
+    where
+        Self: Len,
+    {
+        Self::LEN
+    }
+
+    fn is_empty(&self) -> bool
+    where
+        Self: Len,
+    {
+        Self::LEN == 0
+    }
+
+    fn get<N>(&self) -> &<Self as Get<N>>::Output
+    where
+        Self: Get<N>,
+    {
+        Get::get(self)
+    }
+
+    fn get_mut<N>(&mut self) -> &mut <Self as Get<N>>::Output
+    where
+        Self: GetMut<N>,
+    {
+        GetMut::get_mut(self)
+    }
+
+    fn get_path<P>(&self) -> &<Self as GetPath<P>>::Output
+    where
+        Self: GetPath<P>,
+    {
+        GetPath::get_path(self)
+    }
+
+    fn get_path_mut<P>(&mut self) -> &mut <Self as GetPath<P>>::Output
+    where
+        Self: GetPathMut<P>,
+    {
+        GetPathMut::get_path_mut(self)
+    }
+
+    fn update_path<P, T>(self, value: T) -> (Self::Replaced, Self::Output)
+    where
+        Self: UpdatePath<P, T> + Sized,
+    {
+        UpdatePath::update_path(self, value)
+    }
+
+    fn get_checked<N>(&self) -> &<Self as GetChecked<N>>::Output
+    where
+        Self: GetChecked<N>,
+    {
+        GetChecked::get_checked(self)
+    }
+
+    fn get_optional<N>(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_>
+    where
+        Self: GetOptional<N>,
+    {
+        GetOptional::get_optional(self)
+    }
+
+    fn get_optional_mut<N>(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_>
+    where
+        Self: GetOptionalMut<N>,
+    {
+        GetOptionalMut::get_optional_mut(self)
+    }
+
+    fn push<T>(self, value: T) -> Cons<T, Self>
+    where
+        Self: Sized,
+    {
+        Cons(value, self)
+    }
+
+    fn push_nested<L: HList>(self, list: L) -> Nested<L, Self>
+    where
+        Self: Sized,
+    {
+        Nested(list, self)
+    }
+
+    fn pop(self) -> (Self::Removed, Self::Output)
+    where
+        Self: Remove<Zero> + Sized,
+    {
+        Remove::remove(self)
+    }
+
+    fn insert<N, T>(self, value: T) -> Self::Output
+    where
+        Self: Insert<N, T> + Sized,
+    {
+        Insert::insert(self, value)
+    }
+
+    fn replace<N, T>(self, value: T) -> (Self::Replaced, Self::Output)
+    where
+        Self: Replace<N, T> + Sized,
+    {
+        Replace::replace(self, value)
+    }
+
+    fn map_at<N, F>(self, f: F) -> <Self as MapAt<N, F>>::Output
+    where
+        Self: MapAt<N, F> + Sized,
+    {
+        MapAt::map_at(self, f)
+    }
+
+    fn swap<N, M>(self) -> <Self as Swap<N, M>>::Output
+    where
+        Self: Swap<N, M> + Sized,
+    {
+        Swap::swap(self)
+    }
+
+    fn rotate_left<K>(self) -> <Self as RotateLeft<K>>::Output
+    where
+        Self: RotateLeft<K> + Sized,
+    {
+        RotateLeft::rotate_left(self)
+    }
+
+    fn rotate_right<K>(self) -> <Self as RotateRight<K>>::Output
+    where
+        Self: RotateRight<K> + Sized,
+    {
+        RotateRight::rotate_right(self)
+    }
+
+    fn remove<N>(self) -> (Self::Removed, Self::Output)
+    where
+        Self: Remove<N> + Sized,
+    {
+        Remove::remove(self)
+    }
+
+    fn remove_optional<N>(self) -> (Self::Optional, Self::Output)
+    where
+        Self: RemoveOptional<N> + Sized,
+    {
+        RemoveOptional::remove_optional(self)
+    }
+
+    fn push_back<T>(self, value: T) -> <Self as Append<Cons<T, Nil>>>::Output
+    where
+        Self: Append<Cons<T, Nil>> + Sized,
+    {
+        Append::append(self, Cons(value, Nil))
+    }
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output)
+    where
+        Self: Init + Sized,
+    {
+        Init::pop_back(self)
+    }
+
+    fn last(&self) -> &<Self as Last>::Output
+    where
+        Self: Last,
+    {
+        Last::last(self)
+    }
+
+    fn last_mut(&mut self) -> &mut <Self as Last>::Output
+    where
+        Self: Last,
+    {
+        Last::last_mut(self)
+    }
+
+    fn zip<Rhs>(self, rhs: Rhs) -> <Self as Zip<Rhs>>::Output
+    where
+        Self: Zip<Rhs> + Sized,
+    {
+        Zip::zip(self, rhs)
+    }
+
+    fn zip_ref<'a, 'b, Rhs>(
+        &'a self,
+        rhs: &'b Rhs,
+    ) -> <<Self as AsRefList<'a>>::Output as Zip<<Rhs as AsRefList<'b>>::Output>>::Output
+    where
+        Self: AsRefList<'a>,
+        Rhs: AsRefList<'b>,
+        <Self as AsRefList<'a>>::Output: Zip<<Rhs as AsRefList<'b>>::Output>,
+    {
+        Zip::zip(self.as_ref_list(), rhs.as_ref_list())
+    }
+
+    fn unzip(self) -> (Self::Left, Self::Right)
+    where
+        Self: Unzip + Sized,
+    {
+        Unzip::unzip(self)
+    }
+
+    fn split_at<N>(self) -> (Self::Prefix, Self::Suffix)
+    where
+        Self: SplitAt<N> + Sized,
+    {
+        SplitAt::split_at(self)
+    }
+
+    fn split_at_ref<'a, N>(
+        &'a self,
+    ) -> (
+        <<Self as AsRefList<'a>>::Output as SplitAt<N>>::Prefix,
+        <<Self as AsRefList<'a>>::Output as SplitAt<N>>::Suffix,
+    )
+    where
+        Self: AsRefList<'a>,
+        <Self as AsRefList<'a>>::Output: SplitAt<N>,
+    {
+        self.as_ref_list().split_at()
+    }
+
+    fn split_at_mut<'a, N>(
+        &'a mut self,
+    ) -> (
+        <<Self as AsMutList<'a>>::Output as SplitAt<N>>::Prefix,
+        <<Self as AsMutList<'a>>::Output as SplitAt<N>>::Suffix,
+    )
+    where
+        Self: AsMutList<'a>,
+        <Self as AsMutList<'a>>::Output: SplitAt<N>,
+    {
+        self.as_mut_list().split_at()
+    }
+
+    fn take<N>(self) -> <Self as Take<N>>::Output
+    where
+        Self: Take<N> + Sized,
+    {
+        Take::take(self)
+    }
+
+    fn take_ref<'a, N>(&'a self) -> <<Self as AsRefList<'a>>::Output as Take<N>>::Output
+    where
+        Self: AsRefList<'a>,
+        <Self as AsRefList<'a>>::Output: Take<N>,
+    {
+        self.as_ref_list().take()
+    }
+
+    fn take_mut<'a, N>(&'a mut self) -> <<Self as AsMutList<'a>>::Output as Take<N>>::Output
+    where
+        Self: AsMutList<'a>,
+        <Self as AsMutList<'a>>::Output: Take<N>,
+    {
+        self.as_mut_list().take()
+    }
+
+    fn skip<N>(self) -> <Self as Skip<N>>::Output
+    where
+        Self: Skip<N> + Sized,
+    {
+        Skip::skip(self)
+    }
+
+    fn skip_ref<'a, N>(&'a self) -> <<Self as AsRefList<'a>>::Output as Skip<N>>::Output
+    where
+        Self: AsRefList<'a>,
+        <Self as AsRefList<'a>>::Output: Skip<N>,
+    {
+        Skip::skip(self.as_ref_list())
+    }
+
+    fn skip_mut<'a, N>(&'a mut self) -> <<Self as AsMutList<'a>>::Output as Skip<N>>::Output
+    where
+        Self: AsMutList<'a>,
+        <Self as AsMutList<'a>>::Output: Skip<N>,
+    {
+        Skip::skip(self.as_mut_list())
+    }
+
+    fn slice<'a, Start, End>(
+        &'a self,
+    ) -> <<Self as AsRefList<'a>>::Output as Slice<Start, End>>::Output
+    where
+        Self: AsRefList<'a>,
+        <Self as AsRefList<'a>>::Output: Slice<Start, End>,
+    {
+        Slice::slice(self.as_ref_list())
+    }
+
+    fn slice_mut<'a, Start, End>(
+        &'a mut self,
+    ) -> <<Self as AsMutList<'a>>::Output as Slice<Start, End>>::Output
+    where
+        Self: AsMutList<'a>,
+        <Self as AsMutList<'a>>::Output: Slice<Start, End>,
+    {
+        Slice::slice(self.as_mut_list())
+    }
+
+    fn append<Rhs>(self, rhs: Rhs) -> Self::Output
+    where
+        Self: Append<Rhs> + Sized,
+    {
+        Append::append(self, rhs)
+    }
+
+    fn concat(self) -> Self::Output
+    where
+        Self: Concat + Sized,
+    {
+        Concat::concat(self)
+    }
+
+    fn flatten(self) -> Self::Output
+    where
+        Self: Flatten + Sized,
+    {
+        Flatten::flatten(self)
+    }
+
+    fn flatten_once(self) -> Self::Output
+    where
+        Self: FlattenOnce + Sized,
+    {
+        FlattenOnce::flatten_once(self)
+    }
+
+    fn nest<N>(self) -> <Self as Nest<N>>::Output
+    where
+        Self: Nest<N> + Sized,
+    {
+        Nest::nest(self)
+    }
+
+    fn reverse(self) -> Self::Output
+    where
+        Self: Reverse + Sized,
+    {
+        Reverse::reverse(self)
+    }
+
+    fn iter<'a>(&'a self) -> <&'a Self as IntoIterator>::IntoIter
+    where
+        &'a Self: IntoIterator,
+    {
+        self.into_iter()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> <&'a mut Self as IntoIterator>::IntoIter
+    where
+        &'a mut Self: IntoIterator,
+    {
+        self.into_iter()
+    }
+}
+
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::Nil
+    };
+    ($head:expr) => {
+        $crate::Cons::new($head, $crate::Nil)
+    };
+    ($head:expr, $($tail:expr),+ $(,)?) => {
+        $crate::Cons::new($head, $crate::list!($($tail),+))
+    };
+}
+
+#[macro_export]
+macro_rules! List {
+    () => {
+        $crate::Nil
+    };
+    (.. $tail:ty $(,)?) => {
+        $tail
+    };
+    ($head:ty $(,)?) => {
+        $crate::Cons<$head, $crate::Nil>
+    };
+    ($head:ty, $($tail:tt)+) => {
+        $crate::Cons<$head, $crate::List!($($tail)+)>
+    };
+}
+
+// Assertions
+#[diagnostic::on_unimplemented(message = "`{Self}` and `{T}` are not the same type")]
+pub trait TypeEq<T: ?Sized> {}
+
+impl<T: ?Sized> TypeEq<T> for T {}
+
+#[macro_export]
+macro_rules! assert_nat_eq {
+    ($left:ty, $right:ty $(,)?) => {
+        const _: () = ::core::assert!(
+            <$left as $crate::Nat>::VALUE == <$right as $crate::Nat>::VALUE,
+            ::core::concat!(
+                "assertion failed: `",
+                ::core::stringify!($left),
+                "` == `",
+                ::core::stringify!($right),
+                "`"
+            ),
+        );
+    };
+}
+
+#[macro_export]
+macro_rules! assert_type_eq {
+    ($left:ty, $right:ty $(,)?) => {
+        const _: () = {
+            fn assert_type_eq<Left: ?Sized + $crate::TypeEq<Right>, Right: ?Sized>() {}
+            let _ = assert_type_eq::<$left, $right>;
+        };
+    };
+}
+
+#[macro_export]
+macro_rules! assert_len {
+    ($list:ty, $len:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            <$list as $crate::Len>::LEN == $len,
+            ::core::concat!(
+                "assertion failed: `",
+                ::core::stringify!($list),
+                "` has length `",
+                ::core::stringify!($len),
+                "`"
+            ),
+        );
+    };
+}
+
+#[macro_export]
+macro_rules! assert_impl {
+    ($ty:ty: $($bound:tt)+) => {
+        const _: () = {
+            fn assert_impl<T: ?Sized + $($bound)+>() {}
+            let _ = assert_impl::<$ty>;
+        };
+    };
+}
+
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Nil;
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Cons<Head, Tail>(pub Head, pub Tail);
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Nested<List, Tail>(pub List, pub Tail);
+
+impl Nil {}
+impl<Head, Tail> Cons<Head, Tail> {}
+
+impl HList for Nil {}
+impl<Head, Tail: HList> HList for Cons<Head, Tail> {}
+impl<List: HList, Tail: HList> HList for Nested<List, Tail> {}
+
+impl<Head, Tail> Cons<Head, Tail> {
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Cons(head, tail)
+    }
+}
+
+impl<List, Tail> Nested<List, Tail> {
+    pub fn new(list: List, tail: Tail) -> Self {
+        Nested(list, tail)
+    }
+}
+
+pub trait Len {
+    type Len: Nat;
+
+    const LEN: usize;
+}
+
+impl Len for Nil {
+    type Len = Zero;
+
+    const LEN: usize = 0;
+}
+
+impl<Head, Tail: Len> Len for Cons<Head, Tail> {
+    type Len = Succ<Tail::Len>;
+
+    const LEN: usize = <Self::Len as Nat>::VALUE;
+}
+
+impl<List, Tail: Len> Len for Nested<List, Tail> {
+    type Len = Succ<Tail::Len>;
+
+    const LEN: usize = <Self::Len as Nat>::VALUE;
+}
+
+pub trait Get<N> {
+    type Output;
+
+    fn get(&self) -> &Self::Output;
+}
+
+impl<Head, Tail> Get<Zero> for Cons<Head, Tail> {
+    type Output = Head;
+
+    fn get(&self) -> &Self::Output {
+        &self.0
+    }
+}
+
+impl<N: Nat, Head, Tail> Get<Succ<N>> for Cons<Head, Tail>
+where
+    Tail: Get<N>,
+{
+    type Output = <Tail as Get<N>>::Output;
+
+    fn get(&self) -> &Self::Output {
+        self.1.get()
+    }
+}
+
+impl<List, Tail> Get<Zero> for Nested<List, Tail> {
+    type Output = List;
+
+    fn get(&self) -> &Self::Output {
+        &self.0
+    }
+}
+
+impl<N: Nat, List, Tail> Get<Succ<N>> for Nested<List, Tail>
+where
+    Tail: Get<N>,
+{
+    type Output = <Tail as Get<N>>::Output;
+
+    fn get(&self) -> &Self::Output {
+        self.1.get()
+    }
+}
+
+pub trait GetMut<N>: Get<N> {
+    fn get_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<Head, Tail> GetMut<Zero> for Cons<Head, Tail> {
+    fn get_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+}
+
+impl<N: Nat, Head, Tail> GetMut<Succ<N>> for Cons<Head, Tail>
+where
+    Tail: GetMut<N>,
+{
+    fn get_mut(&mut self) -> &mut Self::Output {
+        self.1.get_mut()
+    }
+}
+
+impl<List, Tail> GetMut<Zero> for Nested<List, Tail> {
+    fn get_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+}
+
+impl<N: Nat, List, Tail> GetMut<Succ<N>> for Nested<List, Tail>
+where
+    Tail: GetMut<N>,
+{
+    fn get_mut(&mut self) -> &mut Self::Output {
+        self.1.get_mut()
+    }
+}
+
+pub trait GetChecked<N> {
+    type Output;
+
+    fn get_checked(&self) -> &Self::Output;
+}
+
+impl<L, N> GetChecked<N> for L
+where
+    L: private::GetCheckedStep<N, N, Zero>,
+{
+    type Output = <L as private::GetCheckedStep<N, N, Zero>>::Output;
+
+    fn get_checked(&self) -> &Self::Output {
+        self.get_checked_step()
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct OutOfBounds<Index>(PhantomData<Index>);
+
+pub trait GetOptional<N> {
+    type Output;
+    type Optional: Optional<Self::Output>;
+
+    fn get_optional(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_>;
+}
+
+pub trait GetOptionalMut<N>: GetOptional<N> {
+    fn get_optional_mut(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_>;
+}
+
+impl<L, N> GetOptional<N> for L
+where
+    L: private::GetOptionalStep<N, N>,
+{
+    type Output = <L as private::GetOptionalStep<N, N>>::Output;
+    type Optional = <L as private::GetOptionalStep<N, N>>::Optional;
+
+    fn get_optional(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
+        self.get_optional_step()
+    }
+}
+
+impl<L, N> GetOptionalMut<N> for L
+where
+    L: private::GetOptionalMutStep<N, N>,
+{
+    fn get_optional_mut(&mut self) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
+        self.get_optional_mut_step()
+    }
+}
+
+pub trait GetPath<P> {
+    type Output;
+
+    fn get_path(&self) -> &Self::Output;
+}
+
+impl<L> GetPath<Nil> for L {
+    type Output = L;
+
+    fn get_path(&self) -> &Self::Output {
+        self
+    }
+}
+
+impl<L, N: 'static, Rest> GetPath<Cons<N, Rest>> for L
+where
+    L: Get<N>,
+    <L as Get<N>>::Output: GetPath<Rest>,
+{
+    type Output = <<L as Get<N>>::Output as GetPath<Rest>>::Output;
+
+    fn get_path(&self) -> &Self::Output {
+        self.get().get_path()
+    }
+}
+
+pub trait GetPathMut<P>: GetPath<P> {
+    fn get_path_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<L> GetPathMut<Nil> for L {
+    fn get_path_mut(&mut self) -> &mut Self::Output {
+        self
+    }
+}
+
+impl<L, N: 'static, Rest> GetPathMut<Cons<N, Rest>> for L
+where
+    L: GetMut<N>,
+    <L as Get<N>>::Output: GetPathMut<Rest>,
+{
+    fn get_path_mut(&mut self) -> &mut Self::Output {
+        self.get_mut().get_path_mut()
+    }
+}
+
+pub trait UpdatePath<P, T> {
+    type Replaced;
+    type Output;
+
+    fn update_path(self, value: T) -> (Self::Replaced, Self::Output);
+}
+
+impl<L, T> UpdatePath<Nil, T> for L {
+    type Replaced = L;
+    type Output = T;
+
+    fn update_path(self, value: T) -> (Self::Replaced, Self::Output) {
+        (self, value)
+    }
+}
+
+impl<L, N, Rest, T> UpdatePath<Cons<N, Rest>, T> for L
+where
+    L: private::UpdateAt<N, Rest, T>,
+{
+    type Replaced = <L as private::UpdateAt<N, Rest, T>>::Replaced;
+    type Output = <L as private::UpdateAt<N, Rest, T>>::Output;
+
+    fn update_path(self, value: T) -> (Self::Replaced, Self::Output) {
+        self.update_at(value)
+    }
+}
+
+pub trait Insert<N, T> {
+    type Output;
+
+    fn insert(self, value: T) -> Self::Output;
+}
+
+impl<L, T> Insert<Zero, T> for L {
+    type Output = Cons<T, L>;
+
+    fn insert(self, value: T) -> Self::Output {
+        Cons(value, self)
+    }
+}
+
+impl<N: Nat, Head, Tail, T> Insert<Succ<N>, T> for Cons<Head, Tail>
+where
+    Tail: Insert<N, T>,
+{
+    type Output = Cons<Head, <Tail as Insert<N, T>>::Output>;
+
+    fn insert(self, value: T) -> Self::Output {
+        Cons(self.0, self.1.insert(value))
+    }
+}
+
+impl<N: Nat, List, Tail, T> Insert<Succ<N>, T> for Nested<List, Tail>
+where
+    Tail: Insert<N, T>,
+{
+    type Output = Nested<List, <Tail as Insert<N, T>>::Output>;
+
+    fn insert(self, value: T) -> Self::Output {
+        Nested(self.0, self.1.insert(value))
+    }
+}
+
+pub trait Replace<N, T> {
+    type Replaced;
+    type Output;
+
+    fn replace(self, value: T) -> (Self::Replaced, Self::Output);
+}
+
+impl<Head, Tail, T> Replace<Zero, T> for Cons<Head, Tail> {
+    type Replaced = Head;
+    type Output = Cons<T, Tail>;
+
+    fn replace(self, value: T) -> (Self::Replaced, Self::Output) {
+        (self.0, Cons(value, self.1))
+    }
+}
+
+impl<N: Nat, Head, Tail, T> Replace<Succ<N>, T> for Cons<Head, Tail>
+where
+    Tail: Replace<N, T>,
+{
+    type Replaced = <Tail as Replace<N, T>>::Replaced;
+    type Output = Cons<Head, <Tail as Replace<N, T>>::Output>;
+
+    fn replace(self, value: T) -> (Self::Replaced, Self::Output) {
+        let (replaced, tail) = self.1.replace(value);
+        (replaced, Cons(self.0, tail))
+    }
+}
+
+impl<List, Tail, T> Replace<Zero, T> for Nested<List, Tail> {
+    type Replaced = List;
+    type Output = Cons<T, Tail>;
+
+    fn replace(self, value: T) -> (Self::Replaced, Self::Output) {
+        (self.0, Cons(value, self.1))
+    }
+}
+
+impl<N: Nat, List, Tail, T> Replace<Succ<N>, T> for Nested<List, Tail>
+where
+    Tail: Replace<N, T>,
+{
+    type Replaced = <Tail as Replace<N, T>>::Replaced;
+    type Output = Nested<List, <Tail as Replace<N, T>>::Output>;
+
+    fn replace(self, value: T) -> (Self::Replaced, Self::Output) {
+        let (replaced, tail) = self.1.replace(value);
+        (replaced, Nested(self.0, tail))
+    }
+}
+
+pub trait MapAt<N, F> {
+    type Output;
+
+    fn map_at(self, f: F) -> Self::Output;
+}
+
+impl<Head, Tail, F, T> MapAt<Zero, F> for Cons<Head, Tail>
+where
+    F: FnOnce(Head) -> T,
+{
+    type Output = Cons<T, Tail>;
+
+    fn map_at(self, f: F) -> Self::Output {
+        Cons(f(self.0), self.1)
+    }
+}
+
+impl<N: Nat, Head, Tail, F> MapAt<Succ<N>, F> for Cons<Head, Tail>
+where
+    Tail: MapAt<N, F>,
+{
+    type Output = Cons<Head, <Tail as MapAt<N, F>>::Output>;
+
+    fn map_at(self, f: F) -> Self::Output {
+        Cons(self.0, self.1.map_at(f))
+    }
+}
+
+impl<List, Tail, F, T> MapAt<Zero, F> for Nested<List, Tail>
+where
+    F: FnOnce(List) -> T,
+{
+    type Output = Cons<T, Tail>;
+
+    fn map_at(self, f: F) -> Self::Output {
+        Cons(f(self.0), self.1)
+    }
+}
+
+impl<N: Nat, List, Tail, F> MapAt<Succ<N>, F> for Nested<List, Tail>
+where
+    Tail: MapAt<N, F>,
+{
+    type Output = Nested<List, <Tail as MapAt<N, F>>::Output>;
+
+    fn map_at(self, f: F) -> Self::Output {
+        Nested(self.0, self.1.map_at(f))
+    }
+}
+
+pub trait Swap<N, M> {
+    type Output;
+
+    fn swap(self) -> Self::Output;
+}
+
+impl<L, N: Nat, M: Nat> Swap<N, M> for L
+where
+    N: Cmp<M>,
+    <N as Cmp<M>>::Output: private::SwapStep<L, N, M>,
+{
+    type Output = <<N as Cmp<M>>::Output as private::SwapStep<L, N, M>>::Output;
+
+    fn swap(self) -> Self::Output {
+        <<N as Cmp<M>>::Output as private::SwapStep<L, N, M>>::swap_step(self)
+    }
+}
+
+pub trait RotateLeft<K> {
+    type Output;
+
+    fn rotate_left(self) -> Self::Output;
+}
+
+impl<L, K> RotateLeft<K> for L
+where
+    L: SplitAt<K>,
+    <L as SplitAt<K>>::Suffix: Append<<L as SplitAt<K>>::Prefix>,
+{
+    type Output = <<L as SplitAt<K>>::Suffix as Append<<L as SplitAt<K>>::Prefix>>::Output;
+
+    fn rotate_left(self) -> Self::Output {
+        let (prefix, suffix) = self.split_at();
+        suffix.append(prefix)
+    }
+}
+
+pub trait RotateRight<K> {
+    type Output;
+
+    fn rotate_right(self) -> Self::Output;
+}
+
+impl<L, K: Nat> RotateRight<K> for L
+where
+    L: Len,
+    L::Len: Sub<K>,
+    L: RotateLeft<<L::Len as Sub<K>>::Output>,
+{
+    type Output = <L as RotateLeft<<L::Len as Sub<K>>::Output>>::Output;
+
+    fn rotate_right(self) -> Self::Output {
+        self.rotate_left()
+    }
+}
+
+pub trait Remove<N> {
+    type Removed;
+    type Output;
+
+    fn remove(self) -> (Self::Removed, Self::Output);
+}
+
+impl<Head, Tail> Remove<Zero> for Cons<Head, Tail> {
+    type Removed = Head;
+    type Output = Tail;
+
+    fn remove(self) -> (Self::Removed, Self::Output) {
+        (self.0, self.1)
+    }
+}
+
+impl<N: Nat, Head, Tail> Remove<Succ<N>> for Cons<Head, Tail>
+where
+    Tail: Remove<N>,
+{
+    type Removed = <Tail as Remove<N>>::Removed;
+    type Output = Cons<Head, <Tail as Remove<N>>::Output>;
+
+    fn remove(self) -> (Self::Removed, Self::Output) {
+        let (removed, tail) = self.1.remove();
+        (removed, Cons(self.0, tail))
+    }
+}
+
+impl<List, Tail> Remove<Zero> for Nested<List, Tail> {
+    type Removed = List;
+    type Output = Tail;
+
+    fn remove(self) -> (Self::Removed, Self::Output) {
+        (self.0, self.1)
+    }
+}
+
+impl<N: Nat, List, Tail> Remove<Succ<N>> for Nested<List, Tail>
+where
+    Tail: Remove<N>,
+{
+    type Removed = <Tail as Remove<N>>::Removed;
+    type Output = Nested<List, <Tail as Remove<N>>::Output>;
+
+    fn remove(self) -> (Self::Removed, Self::Output) {
+        let (removed, tail) = self.1.remove();
+        (removed, Nested(self.0, tail))
+    }
+}
+
+pub trait Last {
+    type Output;
+
+    fn last(&self) -> &Self::Output;
+
+    fn last_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<Head> Last for Cons<Head, Nil> {
+    type Output = Head;
+
+    fn last(&self) -> &Self::Output {
+        &self.0
+    }
+
+    fn last_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+}
+
+impl<Head, Next, Tail> Last for Cons<Head, Cons<Next, Tail>>
+where
+    Cons<Next, Tail>: Last,
+{
+    type Output = <Cons<Next, Tail> as Last>::Output;
+
+    fn last(&self) -> &Self::Output {
+        self.1.last()
+    }
+
+    fn last_mut(&mut self) -> &mut Self::Output {
+        self.1.last_mut()
+    }
+}
+
+impl<List> Last for Nested<List, Nil> {
+    type Output = List;
+
+    fn last(&self) -> &Self::Output {
+        &self.0
+    }
+
+    fn last_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+}
+
+impl<Head, Next, Tail> Last for Cons<Head, Nested<Next, Tail>>
+where
+    Nested<Next, Tail>: Last,
+{
+    type Output = <Nested<Next, Tail> as Last>::Output;
+
+    fn last(&self) -> &Self::Output {
+        self.1.last()
+    }
+
+    fn last_mut(&mut self) -> &mut Self::Output {
+        self.1.last_mut()
+    }
+}
+
+impl<List, Next, Tail> Last for Nested<List, Cons<Next, Tail>>
+where
+    Cons<Next, Tail>: Last,
+{
+    type Output = <Cons<Next, Tail> as Last>::Output;
+
+    fn last(&self) -> &Self::Output {
+        self.1.last()
+    }
+
+    fn last_mut(&mut self) -> &mut Self::Output {
+        self.1.last_mut()
+    }
+}
+
+impl<List, Next, Tail> Last for Nested<List, Nested<Next, Tail>>
+where
+    Nested<Next, Tail>: Last,
+{
+    type Output = <Nested<Next, Tail> as Last>::Output;
+
+    fn last(&self) -> &Self::Output {
+        self.1.last()
+    }
+
+    fn last_mut(&mut self) -> &mut Self::Output {
+        self.1.last_mut()
+    }
+}
+
+pub trait Init: Last {
+    type Output;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output);
+}
+
+impl<Head> Init for Cons<Head, Nil> {
+    type Output = Nil;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output) {
+        (self.0, Nil)
+    }
+}
+
+impl<Head, Next, Tail> Init for Cons<Head, Cons<Next, Tail>>
+where
+    Cons<Next, Tail>: Init,
+{
+    type Output = Cons<Head, <Cons<Next, Tail> as Init>::Output>;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output) {
+        let (last, init) = self.1.pop_back();
+        (last, Cons(self.0, init))
+    }
+}
+
+impl<List> Init for Nested<List, Nil> {
+    type Output = Nil;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output) {
+        (self.0, Nil)
+    }
+}
+
+impl<Head, Next, Tail> Init for Cons<Head, Nested<Next, Tail>>
+where
+    Nested<Next, Tail>: Init,
+{
+    type Output = Cons<Head, <Nested<Next, Tail> as Init>::Output>;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output) {
+        let (last, init) = self.1.pop_back();
+        (last, Cons(self.0, init))
+    }
+}
+
+impl<List, Next, Tail> Init for Nested<List, Cons<Next, Tail>>
+where
+    Cons<Next, Tail>: Init,
+{
+    type Output = Nested<List, <Cons<Next, Tail> as Init>::Output>;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output) {
+        let (last, init) = self.1.pop_back();
+        (last, Nested(self.0, init))
+    }
+}
+
+impl<List, Next, Tail> Init for Nested<List, Nested<Next, Tail>>
+where
+    Nested<Next, Tail>: Init,
+{
+    type Output = Nested<List, <Nested<Next, Tail> as Init>::Output>;
+
+    fn pop_back(self) -> (<Self as Last>::Output, <Self as Init>::Output) {
+        let (last, init) = self.1.pop_back();
+        (last, Nested(self.0, init))
+    }
+}
+
+#[diagnostic::on_unimplemented(message = "`{Self}` and `{Rhs}` do not have the same length")]
+pub trait Zip<Rhs> {
+    type Output;
+
+    fn zip(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Zip<Nil> for Nil {
+    type Output = Nil;
+
+    fn zip(self, _: Nil) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail, RhsHead, RhsTail> Zip<Cons<RhsHead, RhsTail>> for Cons<Head, Tail>
+where
+    Tail: Zip<RhsTail>,
+{
+    type Output = Cons<(Head, RhsHead), <Tail as Zip<RhsTail>>::Output>;
+
+    fn zip(self, rhs: Cons<RhsHead, RhsTail>) -> Self::Output {
+        Cons((self.0, rhs.0), self.1.zip(rhs.1))
+    }
+}
+
+impl<Head, Tail, RhsList, RhsTail> Zip<Nested<RhsList, RhsTail>> for Cons<Head, Tail>
+where
+    Tail: Zip<RhsTail>,
+{
+    type Output = Cons<(Head, RhsList), <Tail as Zip<RhsTail>>::Output>;
+
+    fn zip(self, rhs: Nested<RhsList, RhsTail>) -> Self::Output {
+        Cons((self.0, rhs.0), self.1.zip(rhs.1))
+    }
+}
+
+impl<List, Tail, RhsHead, RhsTail> Zip<Cons<RhsHead, RhsTail>> for Nested<List, Tail>
+where
+    Tail: Zip<RhsTail>,
+{
+    type Output = Cons<(List, RhsHead), <Tail as Zip<RhsTail>>::Output>;
+
+    fn zip(self, rhs: Cons<RhsHead, RhsTail>) -> Self::Output {
+        Cons((self.0, rhs.0), self.1.zip(rhs.1))
+    }
+}
+
+impl<List, Tail, RhsList, RhsTail> Zip<Nested<RhsList, RhsTail>> for Nested<List, Tail>
+where
+    Tail: Zip<RhsTail>,
+{
+    type Output = Cons<(List, RhsList), <Tail as Zip<RhsTail>>::Output>;
+
+    fn zip(self, rhs: Nested<RhsList, RhsTail>) -> Self::Output {
+        Cons((self.0, rhs.0), self.1.zip(rhs.1))
+    }
+}
+
+pub trait Unzip {
+    type Left;
+    type Right;
+
+    fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+impl Unzip for Nil {
+    type Left = Nil;
+    type Right = Nil;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        (Nil, Nil)
+    }
+}
+
+impl<A, B, Tail> Unzip for Cons<(A, B), Tail>
+where
+    Tail: Unzip,
+{
+    type Left = Cons<A, Tail::Left>;
+    type Right = Cons<B, Tail::Right>;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let ((a, b), tail) = (self.0, self.1);
+        let (left, right) = tail.unzip();
+        (Cons(a, left), Cons(b, right))
+    }
+}
+
+pub trait SplitAt<N> {
+    type Prefix;
+    type Suffix;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix);
+}
+
+impl<L> SplitAt<Zero> for L {
+    type Prefix = Nil;
+    type Suffix = L;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        (Nil, self)
+    }
+}
+
+impl<N: Nat, Head, Tail> SplitAt<Succ<N>> for Cons<Head, Tail>
+where
+    Tail: SplitAt<N>,
+{
+    type Prefix = Cons<Head, <Tail as SplitAt<N>>::Prefix>;
+    type Suffix = <Tail as SplitAt<N>>::Suffix;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        let (prefix, suffix) = self.1.split_at();
+        (Cons(self.0, prefix), suffix)
+    }
+}
+
+impl<N: Nat, List, Tail> SplitAt<Succ<N>> for Nested<List, Tail>
+where
+    Tail: SplitAt<N>,
+{
+    type Prefix = Nested<List, <Tail as SplitAt<N>>::Prefix>;
+    type Suffix = <Tail as SplitAt<N>>::Suffix;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        let (prefix, suffix) = self.1.split_at();
+        (Nested(self.0, prefix), suffix)
+    }
+}
+
+pub trait Take<N> {
+    type Output;
+
+    fn take(self) -> Self::Output;
+}
+
+impl<N, L> Take<N> for L
+where
+    L: SplitAt<N>,
+{
+    type Output = <L as SplitAt<N>>::Prefix;
+
+    fn take(self) -> Self::Output {
+        self.split_at().0
+    }
+}
+
+pub trait Skip<N> {
+    type Output;
+
+    fn skip(self) -> Self::Output;
+}
+
+impl<N, L> Skip<N> for L
+where
+    L: SplitAt<N>,
+{
+    type Output = <L as SplitAt<N>>::Suffix;
+
+    fn skip(self) -> Self::Output {
+        self.split_at().1
+    }
+}
+
+pub trait Slice<Start, End> {
+    type Output;
+
+    fn slice(self) -> Self::Output;
+}
+
+impl<L, Start: Nat, End: Nat> Slice<Start, End> for L
+where
+    Start: IsLessOrEqual<End>,
+    End: Sub<Start>,
+    L: Skip<Start>,
+    <L as Skip<Start>>::Output: Take<<End as Sub<Start>>::Output>,
+{
+    type Output = <<L as Skip<Start>>::Output as Take<<End as Sub<Start>>::Output>>::Output;
+
+    fn slice(self) -> Self::Output {
+        Take::take(Skip::skip(self))
+    }
+}
+
+pub trait RemoveOptional<N> {
+    type Removed;
+    type Output;
+    type Optional: Optional<Self::Removed>;
+
+    fn remove_optional(self) -> (Self::Optional, Self::Output);
+}
+
+impl<L, N> RemoveOptional<N> for L
+where
+    L: private::RemoveOptionalStep<N, N>,
+{
+    type Removed = <L as private::RemoveOptionalStep<N, N>>::Removed;
+    type Output = <L as private::RemoveOptionalStep<N, N>>::Output;
+    type Optional = <L as private::RemoveOptionalStep<N, N>>::Optional;
+
+    fn remove_optional(self) -> (Self::Optional, Self::Output) {
+        self.remove_optional_step()
+    }
+}
+
+pub trait Append<Rhs> {
+    type Output;
+
+    fn append(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<Rhs> Append<Rhs> for Nil {
+    type Output = Rhs;
+
+    fn append(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<Head, Tail, Rhs> Append<Rhs> for Cons<Head, Tail>
+where
+    Tail: Append<Rhs>,
+{
+    type Output = Cons<Head, <Tail as Append<Rhs>>::Output>;
+
+    fn append(self, rhs: Rhs) -> Self::Output {
+        Cons(self.0, self.1.append(rhs))
+    }
+}
+
+impl<List, Tail, Rhs> Append<Rhs> for Nested<List, Tail>
+where
+    Tail: Append<Rhs>,
+{
+    type Output = Nested<List, <Tail as Append<Rhs>>::Output>;
+
+    fn append(self, rhs: Rhs) -> Self::Output {
+        Nested(self.0, self.1.append(rhs))
+    }
+}
+
+impl<Rhs: HList> std::ops::Add<Rhs> for Nil {
+    type Output = Rhs;
+
+    fn add(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<Head, Tail, Rhs: HList> std::ops::Add<Rhs> for Cons<Head, Tail>
+where
+    Tail: Append<Rhs>,
+{
+    type Output = <Self as Append<Rhs>>::Output;
+
+    fn add(self, rhs: Rhs) -> Self::Output {
+        self.append(rhs)
+    }
+}
+
+impl<List, Tail, Rhs: HList> std::ops::Add<Rhs> for Nested<List, Tail>
+where
+    Tail: Append<Rhs>,
+{
+    type Output = <Self as Append<Rhs>>::Output;
+
+    fn add(self, rhs: Rhs) -> Self::Output {
+        self.append(rhs)
+    }
+}
+
+pub trait Concat {
+    type Output;
+
+    fn concat(self) -> Self::Output;
+}
+
+impl Concat for Nil {
+    type Output = Nil;
+
+    fn concat(self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail> Concat for Cons<Head, Tail>
+where
+    Tail: Concat,
+    Head: Append<<Tail as Concat>::Output>,
+{
+    type Output = <Head as Append<<Tail as Concat>::Output>>::Output;
+
+    fn concat(self) -> Self::Output {
+        self.0.append(self.1.concat())
+    }
+}
+
+impl<List, Tail> Concat for Nested<List, Tail>
+where
+    Tail: Concat,
+    List: Append<<Tail as Concat>::Output>,
+{
+    type Output = <List as Append<<Tail as Concat>::Output>>::Output;
+
+    fn concat(self) -> Self::Output {
+        self.0.append(self.1.concat())
+    }
+}
+
+pub trait Flatten {
+    type Output;
+
+    fn flatten(self) -> Self::Output;
+}
+
+impl Flatten for Nil {
+    type Output = Nil;
+
+    fn flatten(self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail> Flatten for Cons<Head, Tail>
+where
+    Tail: Flatten,
+{
+    type Output = Cons<Head, <Tail as Flatten>::Output>;
+
+    fn flatten(self) -> Self::Output {
+        Cons(self.0, self.1.flatten())
+    }
+}
+
+impl<List, Tail> Flatten for Nested<List, Tail>
+where
+    List: Flatten,
+    Tail: Flatten,
+    <List as Flatten>::Output: Append<<Tail as Flatten>::Output>,
+{
+    type Output = <<List as Flatten>::Output as Append<<Tail as Flatten>::Output>>::Output;
+
+    fn flatten(self) -> Self::Output {
+        Append::append(self.0.flatten(), self.1.flatten())
+    }
+}
+
+pub trait FlattenOnce {
+    type Output;
+
+    fn flatten_once(self) -> Self::Output;
+}
+
+impl FlattenOnce for Nil {
+    type Output = Nil;
+
+    fn flatten_once(self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail> FlattenOnce for Cons<Head, Tail>
+where
+    Tail: FlattenOnce,
+{
+    type Output = Cons<Head, <Tail as FlattenOnce>::Output>;
+
+    fn flatten_once(self) -> Self::Output {
+        Cons(self.0, self.1.flatten_once())
+    }
+}
+
+impl<List, Tail> FlattenOnce for Nested<List, Tail>
+where
+    Tail: FlattenOnce,
+    List: Append<<Tail as FlattenOnce>::Output>,
+{
+    type Output = <List as Append<<Tail as FlattenOnce>::Output>>::Output;
+
+    fn flatten_once(self) -> Self::Output {
+        Append::append(self.0, self.1.flatten_once())
+    }
+}
+
+pub trait Nest<N> {
+    type Output;
+
+    fn nest(self) -> Self::Output;
+}
+
+impl<Head: HList, Tail> Nest<Zero> for Cons<Head, Tail> {
+    type Output = Nested<Head, Tail>;
+
+    fn nest(self) -> Self::Output {
+        Nested(self.0, self.1)
+    }
+}
+
+impl<List, Tail> Nest<Zero> for Nested<List, Tail> {
+    type Output = Self;
+
+    fn nest(self) -> Self::Output {
+        self
+    }
+}
+
+impl<N: Nat, Head, Tail> Nest<Succ<N>> for Cons<Head, Tail>
+where
+    Tail: Nest<N>,
+{
+    type Output = Cons<Head, <Tail as Nest<N>>::Output>;
+
+    fn nest(self) -> Self::Output {
+        Cons(self.0, self.1.nest())
+    }
+}
+
+impl<N: Nat, List, Tail> Nest<Succ<N>> for Nested<List, Tail>
+where
+    Tail: Nest<N>,
+{
+    type Output = Nested<List, <Tail as Nest<N>>::Output>;
+
+    fn nest(self) -> Self::Output {
+        Nested(self.0, self.1.nest())
+    }
+}
+
+pub trait Reverse {
+    type Output;
+
+    fn reverse(self) -> Self::Output;
+}
+
+impl<L> Reverse for L
+where
+    L: private::ReverseOnto<Nil>,
+{
+    type Output = <L as private::ReverseOnto<Nil>>::Output;
+
+    fn reverse(self) -> Self::Output {
+        self.reverse_onto(Nil)
+    }
+}
+
+#[diagnostic::on_unimplemented(message = "not every element of `{Self}` is `{T}`")]
+pub trait All<T> {}
+
+impl<T> All<T> for Nil {}
+impl<T, Tail> All<T> for Cons<T, Tail> where Tail: All<T> {}
+impl<T, Tail> All<T> for Nested<T, Tail> where Tail: All<T> {}
+
+trait PopOptional<T> {
+    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>);
+    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>);
+    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>);
+}
+
+impl<T> PopOptional<T> for Nil {
+    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>) {
+        (None, Box::new(Nil))
+    }
+
+    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>) {
+        (None, self)
+    }
+
+    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>) {
+        (None, self)
+    }
+}
+
+impl<T, Tail> PopOptional<T> for Cons<T, Tail>
+where
+    Tail: PopOptional<T> + 'static,
+{
+    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>) {
+        let Cons(value, tail) = *self;
+        (Some(value), Box::new(tail))
+    }
+
+    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>) {
+        (Some(&self.0), &self.1)
+    }
+
+    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>) {
+        (Some(&mut self.0), &mut self.1)
+    }
+}
+
+impl<T, Tail> PopOptional<T> for Nested<T, Tail>
+where
+    Tail: PopOptional<T> + 'static,
+{
+    fn pop_optional_box(self: Box<Self>) -> (Option<T>, Box<dyn PopOptional<T>>) {
+        let Nested(value, tail) = *self;
+        (Some(value), Box::new(tail))
+    }
+
+    fn pop_optional_ref(&self) -> (Option<&T>, &dyn PopOptional<T>) {
+        (Some(&self.0), &self.1)
+    }
+
+    fn pop_optional_mut(&mut self) -> (Option<&mut T>, &mut dyn PopOptional<T>) {
+        (Some(&mut self.0), &mut self.1)
+    }
+}
+
+pub struct IntoIter<T> {
+    list: Box<dyn PopOptional<T>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        replace_with_and_return(
+            &mut self.list,
+            || Box::new(Nil),
+            PopOptional::pop_optional_box,
+        )
+    }
+}
+
+pub struct Iter<'a, T> {
+    list: &'a dyn PopOptional<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, list) = self.list.pop_optional_ref();
+        self.list = list;
+        value
+    }
+}
+
+pub struct IterMut<'a, T> {
+    list: &'a mut dyn PopOptional<T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        replace_with_and_return(
+            &mut self.list,
+            || Box::leak(Box::new(Nil)),
+            PopOptional::pop_optional_mut,
+        )
+    }
+}
+
+impl IntoIterator for Nil {
+    type Item = ();
+    type IntoIter = IntoIter<()>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            list: Box::new(self),
+        }
+    }
+}
+
+impl<Head, Tail> IntoIterator for Cons<Head, Tail>
+where
+    Head: 'static,
+    Tail: PopOptional<Head> + 'static,
+{
+    type Item = Head;
+    type IntoIter = IntoIter<Head>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            list: Box::new(self),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Nil {
+    type Item = &'a ();
+    type IntoIter = Iter<'a, ()>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { list: self }
+    }
+}
+
+impl<'a, Head, Tail> IntoIterator for &'a Cons<Head, Tail>
+where
+    Head: 'a,
+    Tail: PopOptional<Head> + 'static,
+{
+    type Item = &'a Head;
+    type IntoIter = Iter<'a, Head>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { list: self }
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Nil {
+    type Item = &'a mut ();
+    type IntoIter = IterMut<'a, ()>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut { list: self }
+    }
+}
+
+impl<'a, Head, Tail> IntoIterator for &'a mut Cons<Head, Tail>
+where
+    Head: 'a,
+    Tail: PopOptional<Head> + 'static,
+{
+    type Item = &'a mut Head;
+    type IntoIter = IterMut<'a, Head>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut { list: self }
+    }
+}
+
+impl<List, Tail> IntoIterator for Nested<List, Tail>
+where
+    List: 'static,
+    Tail: PopOptional<List> + 'static,
+{
+    type Item = List;
+    type IntoIter = IntoIter<List>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            list: Box::new(self),
+        }
+    }
+}
+
+impl<'a, List, Tail> IntoIterator for &'a Nested<List, Tail>
+where
+    List: 'a,
+    Tail: PopOptional<List> + 'static,
+{
+    type Item = &'a List;
+    type IntoIter = Iter<'a, List>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { list: self }
+    }
+}
+
+impl<'a, List, Tail> IntoIterator for &'a mut Nested<List, Tail>
+where
+    List: 'a,
+    Tail: PopOptional<List> + 'static,
+{
+    type Item = &'a mut List;
+    type IntoIter = IterMut<'a, List>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut { list: self }
+    }
+}
+
+pub trait AsRefList<'a> {
+    type Output;
+
+    fn as_ref_list(&'a self) -> Self::Output;
+}
+
+pub trait AsMutList<'a> {
+    type Output;
+
+    fn as_mut_list(&'a mut self) -> Self::Output;
+}
+
+impl<'a> AsRefList<'a> for Nil {
+    type Output = Nil;
+
+    fn as_ref_list(&'a self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, Head, Tail> AsRefList<'a> for Cons<Head, Tail>
+where
+    Head: 'a,
+    Tail: AsRefList<'a>,
+{
+    type Output = Cons<&'a Head, <Tail as AsRefList<'a>>::Output>;
+
+    fn as_ref_list(&'a self) -> Self::Output {
+        Cons(&self.0, self.1.as_ref_list())
+    }
+}
+
+impl<'a> AsMutList<'a> for Nil {
+    type Output = Nil;
+
+    fn as_mut_list(&'a mut self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, Head, Tail> AsMutList<'a> for Cons<Head, Tail>
+where
+    Head: 'a,
+    Tail: AsMutList<'a>,
+{
+    type Output = Cons<&'a mut Head, <Tail as AsMutList<'a>>::Output>;
+
+    fn as_mut_list(&'a mut self) -> Self::Output {
+        Cons(&mut self.0, self.1.as_mut_list())
+    }
+}
+
+impl<'a, List, Tail> AsRefList<'a> for Nested<List, Tail>
+where
+    List: 'a,
+    Tail: AsRefList<'a>,
+{
+    type Output = Cons<&'a List, <Tail as AsRefList<'a>>::Output>;
+
+    fn as_ref_list(&'a self) -> Self::Output {
+        Cons(&self.0, self.1.as_ref_list())
+    }
+}
+
+impl<'a, List, Tail> AsMutList<'a> for Nested<List, Tail>
+where
+    List: 'a,
+    Tail: AsMutList<'a>,
+{
+    type Output = Cons<&'a mut List, <Tail as AsMutList<'a>>::Output>;
+
+    fn as_mut_list(&'a mut self) -> Self::Output {
+        Cons(&mut self.0, self.1.as_mut_list())
+    }
+}
+
+pub trait AsDynRef<'a> {
+    type Output: All<&'a dyn Any>;
+
+    fn as_dyn_ref(&'a self) -> Self::Output;
+}
+
+pub trait AsDynMut<'a> {
+    type Output: All<&'a mut dyn Any>;
+
+    fn as_dyn_mut(&'a mut self) -> Self::Output;
+}
+
+pub trait ToDynBox {
+    type Output: All<Box<dyn Any>>;
+
+    fn to_dyn_box(self) -> Self::Output;
+}
+
+impl ToDynBox for Nil {
+    type Output = Nil;
+
+    fn to_dyn_box(self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail> ToDynBox for Cons<Head, Tail>
+where
+    Head: 'static,
+    Tail: ToDynBox,
+{
+    type Output = Cons<Box<dyn Any>, <Tail as ToDynBox>::Output>;
+
+    fn to_dyn_box(self) -> Self::Output {
+        Cons(Box::new(self.0), self.1.to_dyn_box())
+    }
+}
+
+impl<List, Tail> ToDynBox for Nested<List, Tail>
+where
+    List: 'static,
+    Tail: ToDynBox,
+{
+    type Output = Cons<Box<dyn Any>, <Tail as ToDynBox>::Output>;
+
+    fn to_dyn_box(self) -> Self::Output {
+        Cons(Box::new(self.0), self.1.to_dyn_box())
+    }
+}
+
+impl<'a> AsDynRef<'a> for Nil {
+    type Output = Nil;
+
+    fn as_dyn_ref(&'a self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, Head, Tail> AsDynRef<'a> for Cons<Head, Tail>
+where
+    Head: 'static,
+    Tail: AsDynRef<'a>,
+{
+    type Output = Cons<&'a dyn Any, <Tail as AsDynRef<'a>>::Output>;
+
+    fn as_dyn_ref(&'a self) -> Self::Output {
+        Cons(&self.0, self.1.as_dyn_ref())
+    }
+}
+
+impl<'a, List, Tail> AsDynRef<'a> for Nested<List, Tail>
+where
+    List: 'static,
+    Tail: AsDynRef<'a>,
+{
+    type Output = Cons<&'a dyn Any, <Tail as AsDynRef<'a>>::Output>;
+
+    fn as_dyn_ref(&'a self) -> Self::Output {
+        Cons(&self.0, self.1.as_dyn_ref())
+    }
+}
+
+impl<'a> AsDynMut<'a> for Nil {
+    type Output = Nil;
+
+    fn as_dyn_mut(&'a mut self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, Head, Tail> AsDynMut<'a> for Cons<Head, Tail>
+where
+    Head: 'static,
+    Tail: AsDynMut<'a>,
+{
+    type Output = Cons<&'a mut dyn Any, <Tail as AsDynMut<'a>>::Output>;
+
+    fn as_dyn_mut(&'a mut self) -> Self::Output {
+        Cons(&mut self.0, self.1.as_dyn_mut())
+    }
+}
+
+impl<'a, List, Tail> AsDynMut<'a> for Nested<List, Tail>
+where
+    List: 'static,
+    Tail: AsDynMut<'a>,
+{
+    type Output = Cons<&'a mut dyn Any, <Tail as AsDynMut<'a>>::Output>;
+
+    fn as_dyn_mut(&'a mut self) -> Self::Output {
+        Cons(&mut self.0, self.1.as_dyn_mut())
+    }
+}
+
+pub trait AllInto<T> {
+    type Into: All<T>;
+
+    fn into(self) -> Self::Into;
+}
+
+impl<T> AllInto<T> for Nil {
+    type Into = Nil;
+
+    fn into(self) -> Self::Into {
+        Nil
+    }
+}
+
+impl<Head, Tail, T> AllInto<T> for Cons<Head, Tail>
+where
+    Head: Into<T>,
+    Tail: AllInto<T>,
+{
+    type Into = Cons<T, <Tail as AllInto<T>>::Into>;
+
+    fn into(self) -> Self::Into {
+        Cons(self.0.into(), self.1.into())
+    }
+}
+
+impl<List, Tail, T> AllInto<T> for Nested<List, Tail>
+where
+    List: Into<T>,
+    Tail: AllInto<T>,
+{
+    type Into = Cons<T, <Tail as AllInto<T>>::Into>;
+
+    fn into(self) -> Self::Into {
         Cons(self.0.into(), self.1.into())
     }
 }
+
+mod private {
+    use super::*;
+
+    pub trait BinOps {
+        type Inc: Bin;
+        type Double: Bin;
+
+        type AddBin<T: Bin>: Bin;
+        type AddB0<N: Bin>: Bin;
+        type AddB1<N: Bin>: Bin;
+        type MulBin<T: Bin>: Bin;
+    }
+
+    impl BinOps for UTerm {
+        type Inc = B1<UTerm>;
+        type Double = UTerm;
+
+        type AddBin<T: Bin> = T;
+        type AddB0<N: Bin> = B0<N>;
+        type AddB1<N: Bin> = B1<N>;
+        type MulBin<T: Bin> = UTerm;
+    }
+
+    impl<N: Bin> BinOps for B0<N> {
+        type Inc = B1<N>;
+        type Double = B0<Self>;
+
+        type AddBin<T: Bin> = T::AddB0<N>;
+        type AddB0<M: Bin> = B0<M::AddBin<N>>;
+        type AddB1<M: Bin> = B1<M::AddBin<N>>;
+        type MulBin<T: Bin> = <N::MulBin<T> as BinOps>::Double;
+    }
+
+    impl<N: Bin> BinOps for B1<N> {
+        type Inc = B0<N::Inc>;
+        type Double = B0<Self>;
+
+        type AddBin<T: Bin> = T::AddB1<N>;
+        type AddB0<M: Bin> = B1<M::AddBin<N>>;
+        type AddB1<M: Bin> = B0<<M::AddBin<N> as BinOps>::Inc>;
+        type MulBin<T: Bin> = <<N::MulBin<T> as BinOps>::Double as BinOps>::AddBin<T>;
+    }
+
+    pub trait DivRemStep<N: Nat, D: Nat> {
+        type Quotient: Nat;
+        type Remainder: Nat;
+    }
+
+    impl<N: Nat, D: Nat> DivRemStep<N, D> for TNone {
+        type Quotient = Zero;
+        type Remainder = N;
+    }
+
+    impl<N: Nat, D: Nat, R: Nat> DivRemStep<N, D> for TSome<R>
+    where
+        R: DivRem<D>,
+    {
+        type Quotient = Succ<<R as DivRem<D>>::Quotient>;
+        type Remainder = <R as DivRem<D>>::Remainder;
+    }
+
+    pub trait Log2Step: Nat {
+        type Output: Nat;
+    }
+
+    impl Log2Step for Zero {
+        type Output = Zero;
+    }
+
+    impl<N: Nat> Log2Step for Succ<N>
+    where
+        Succ<N>: Log2,
+    {
+        type Output = Succ<<Succ<N> as Log2>::Output>;
+    }
+
+    pub trait SqrtFrom<K: Nat, Odd: Nat>: Nat {
+        type Output: Nat;
+    }
+
+    impl<N: Nat, K: Nat, Odd: Nat> SqrtFrom<K, Odd> for N
+    where
+        N: CheckedSub<Odd>,
+        <N as CheckedSub<Odd>>::Output: SqrtStep<K, Odd>,
+    {
+        type Output = <<N as CheckedSub<Odd>>::Output as SqrtStep<K, Odd>>::Output;
+    }
+
+    pub trait SqrtStep<K: Nat, Odd: Nat> {
+        type Output: Nat;
+    }
+
+    impl<K: Nat, Odd: Nat> SqrtStep<K, Odd> for TNone {
+        type Output = K;
+    }
+
+    impl<K: Nat, Odd: Nat, R: Nat> SqrtStep<K, Odd> for TSome<R>
+    where
+        R: SqrtFrom<Succ<K>, Succ<Succ<Odd>>>,
+    {
+        type Output = <R as SqrtFrom<Succ<K>, Succ<Succ<Odd>>>>::Output;
+    }
+
+    #[diagnostic::on_unimplemented(
+        message = "index `{Index}` is out of bounds for a list of length `{Length}`",
+        label = "index out of bounds"
+    )]
+    pub trait GetCheckedStep<N, Index, Length> {
+        type Output;
+
+        fn get_checked_step(&self) -> &Self::Output;
+    }
+
+    impl<Head, Tail, Index, Length> GetCheckedStep<Zero, Index, Length> for Cons<Head, Tail> {
+        type Output = Head;
+
+        fn get_checked_step(&self) -> &Self::Output {
+            &self.0
+        }
+    }
+
+    impl<N: Nat, Head, Tail, Index, Length: Nat> GetCheckedStep<Succ<N>, Index, Length>
+        for Cons<Head, Tail>
+    where
+        Tail: GetCheckedStep<N, Index, Succ<Length>>,
+    {
+        type Output = <Tail as GetCheckedStep<N, Index, Succ<Length>>>::Output;
+
+        fn get_checked_step(&self) -> &Self::Output {
+            self.1.get_checked_step()
+        }
+    }
+
+    impl<List, Tail, Index, Length> GetCheckedStep<Zero, Index, Length> for Nested<List, Tail> {
+        type Output = List;
+
+        fn get_checked_step(&self) -> &Self::Output {
+            &self.0
+        }
+    }
+
+    impl<N: Nat, List, Tail, Index, Length: Nat> GetCheckedStep<Succ<N>, Index, Length>
+        for Nested<List, Tail>
+    where
+        Tail: GetCheckedStep<N, Index, Succ<Length>>,
+    {
+        type Output = <Tail as GetCheckedStep<N, Index, Succ<Length>>>::Output;
+
+        fn get_checked_step(&self) -> &Self::Output {
+            self.1.get_checked_step()
+        }
+    }
+
+    pub trait GetOptionalStep<N, Index> {
+        type Output;
+        type Optional: Optional<Self::Output>;
+
+        fn get_optional_step(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_>;
+    }
+
+    pub trait GetOptionalMutStep<N, Index>: GetOptionalStep<N, Index> {
+        fn get_optional_mut_step(
+            &mut self,
+        ) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_>;
+    }
+
+    impl<N, Index: 'static> GetOptionalStep<N, Index> for Nil {
+        type Output = OutOfBounds<Index>;
+        type Optional = TNone;
+
+        fn get_optional_step(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
+            TNone
+        }
+    }
+
+    impl<N, Index: 'static> GetOptionalMutStep<N, Index> for Nil {
+        fn get_optional_mut_step(
+            &mut self,
+        ) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
+            TNone
+        }
+    }
+
+    impl<Head, Tail, Index> GetOptionalStep<Zero, Index> for Cons<Head, Tail> {
+        type Output = Head;
+        type Optional = TSome<Head>;
+
+        fn get_optional_step(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
+            TSome(&self.0)
+        }
+    }
+
+    impl<Head, Tail, Index> GetOptionalMutStep<Zero, Index> for Cons<Head, Tail> {
+        fn get_optional_mut_step(
+            &mut self,
+        ) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
+            TSome(&mut self.0)
+        }
+    }
+
+    impl<N: Nat, Head, Tail, Index> GetOptionalStep<Succ<N>, Index> for Cons<Head, Tail>
+    where
+        Tail: GetOptionalStep<N, Index>,
+    {
+        type Output = <Tail as GetOptionalStep<N, Index>>::Output;
+        type Optional = <Tail as GetOptionalStep<N, Index>>::Optional;
+
+        fn get_optional_step(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
+            self.1.get_optional_step()
+        }
+    }
+
+    impl<N: Nat, Head, Tail, Index> GetOptionalMutStep<Succ<N>, Index> for Cons<Head, Tail>
+    where
+        Tail: GetOptionalMutStep<N, Index>,
+    {
+        fn get_optional_mut_step(
+            &mut self,
+        ) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
+            self.1.get_optional_mut_step()
+        }
+    }
+
+    impl<List, Tail, Index> GetOptionalStep<Zero, Index> for Nested<List, Tail> {
+        type Output = List;
+        type Optional = TSome<List>;
+
+        fn get_optional_step(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
+            TSome(&self.0)
+        }
+    }
+
+    impl<List, Tail, Index> GetOptionalMutStep<Zero, Index> for Nested<List, Tail> {
+        fn get_optional_mut_step(
+            &mut self,
+        ) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
+            TSome(&mut self.0)
+        }
+    }
+
+    impl<N: Nat, List, Tail, Index> GetOptionalStep<Succ<N>, Index> for Nested<List, Tail>
+    where
+        Tail: GetOptionalStep<N, Index>,
+    {
+        type Output = <Tail as GetOptionalStep<N, Index>>::Output;
+        type Optional = <Tail as GetOptionalStep<N, Index>>::Optional;
+
+        fn get_optional_step(&self) -> <Self::Optional as Optional<Self::Output>>::AsRef<'_> {
+            self.1.get_optional_step()
+        }
+    }
+
+    impl<N: Nat, List, Tail, Index> GetOptionalMutStep<Succ<N>, Index> for Nested<List, Tail>
+    where
+        Tail: GetOptionalMutStep<N, Index>,
+    {
+        fn get_optional_mut_step(
+            &mut self,
+        ) -> <Self::Optional as Optional<Self::Output>>::AsMut<'_> {
+            self.1.get_optional_mut_step()
+        }
+    }
+
+    pub trait RemoveOptionalStep<N, Index> {
+        type Removed;
+        type Output;
+        type Optional: Optional<Self::Removed>;
+
+        fn remove_optional_step(self) -> (Self::Optional, Self::Output);
+    }
+
+    impl<N, Index> RemoveOptionalStep<N, Index> for Nil {
+        type Removed = OutOfBounds<Index>;
+        type Output = Nil;
+        type Optional = TNone;
+
+        fn remove_optional_step(self) -> (Self::Optional, Self::Output) {
+            (TNone, Nil)
+        }
+    }
+
+    impl<Head, Tail, Index> RemoveOptionalStep<Zero, Index> for Cons<Head, Tail> {
+        type Removed = Head;
+        type Output = Tail;
+        type Optional = TSome<Head>;
+
+        fn remove_optional_step(self) -> (Self::Optional, Self::Output) {
+            (TSome(self.0), self.1)
+        }
+    }
+
+    impl<N, Head, Tail, Index> RemoveOptionalStep<Succ<N>, Index> for Cons<Head, Tail>
+    where
+        N: Nat,
+        Tail: RemoveOptionalStep<N, Index>,
+    {
+        type Removed = <Tail as RemoveOptionalStep<N, Index>>::Removed;
+        type Output = Cons<Head, <Tail as RemoveOptionalStep<N, Index>>::Output>;
+        type Optional = <Tail as RemoveOptionalStep<N, Index>>::Optional;
+
+        fn remove_optional_step(self) -> (Self::Optional, Self::Output) {
+            let (removed, tail) = self.1.remove_optional_step();
+            (removed, Cons(self.0, tail))
+        }
+    }
+
+    impl<List, Tail, Index> RemoveOptionalStep<Zero, Index> for Nested<List, Tail> {
+        type Removed = List;
+        type Output = Tail;
+        type Optional = TSome<List>;
+
+        fn remove_optional_step(self) -> (Self::Optional, Self::Output) {
+            (TSome(self.0), self.1)
+        }
+    }
+
+    impl<N, List, Tail, Index> RemoveOptionalStep<Succ<N>, Index> for Nested<List, Tail>
+    where
+        N: Nat,
+        Tail: RemoveOptionalStep<N, Index>,
+    {
+        type Removed = <Tail as RemoveOptionalStep<N, Index>>::Removed;
+        type Output = Nested<List, <Tail as RemoveOptionalStep<N, Index>>::Output>;
+        type Optional = <Tail as RemoveOptionalStep<N, Index>>::Optional;
+
+        fn remove_optional_step(self) -> (Self::Optional, Self::Output) {
+            let (removed, tail) = self.1.remove_optional_step();
+            (removed, Nested(self.0, tail))
+        }
+    }
+
+    pub trait UpdateAt<N, P, T> {
+        type Replaced;
+        type Output;
+
+        fn update_at(self, value: T) -> (Self::Replaced, Self::Output);
+    }
+
+    impl<Head, Tail, P, T> UpdateAt<Zero, P, T> for Cons<Head, Tail>
+    where
+        Head: UpdatePath<P, T>,
+    {
+        type Replaced = <Head as UpdatePath<P, T>>::Replaced;
+        type Output = Cons<<Head as UpdatePath<P, T>>::Output, Tail>;
+
+        fn update_at(self, value: T) -> (Self::Replaced, Self::Output) {
+            let (replaced, head) = UpdatePath::update_path(self.0, value);
+            (replaced, Cons(head, self.1))
+        }
+    }
+
+    impl<N: Nat, Head, Tail, P, T> UpdateAt<Succ<N>, P, T> for Cons<Head, Tail>
+    where
+        Tail: UpdateAt<N, P, T>,
+    {
+        type Replaced = <Tail as UpdateAt<N, P, T>>::Replaced;
+        type Output = Cons<Head, <Tail as UpdateAt<N, P, T>>::Output>;
+
+        fn update_at(self, value: T) -> (Self::Replaced, Self::Output) {
+            let (replaced, tail) = self.1.update_at(value);
+            (replaced, Cons(self.0, tail))
+        }
+    }
+
+    impl<List, Tail, T> UpdateAt<Zero, Nil, T> for Nested<List, Tail> {
+        type Replaced = List;
+        type Output = Cons<T, Tail>;
+
+        fn update_at(self, value: T) -> (Self::Replaced, Self::Output) {
+            (self.0, Cons(value, self.1))
+        }
+    }
+
+    impl<List, Tail, M, Rest, T> UpdateAt<Zero, Cons<M, Rest>, T> for Nested<List, Tail>
+    where
+        List: UpdatePath<Cons<M, Rest>, T>,
+    {
+        type Replaced = <List as UpdatePath<Cons<M, Rest>, T>>::Replaced;
+        type Output = Nested<<List as UpdatePath<Cons<M, Rest>, T>>::Output, Tail>;
+
+        fn update_at(self, value: T) -> (Self::Replaced, Self::Output) {
+            let (replaced, list) = UpdatePath::update_path(self.0, value);
+            (replaced, Nested(list, self.1))
+        }
+    }
+
+    impl<N: Nat, List, Tail, P, T> UpdateAt<Succ<N>, P, T> for Nested<List, Tail>
+    where
+        Tail: UpdateAt<N, P, T>,
+    {
+        type Replaced = <Tail as UpdateAt<N, P, T>>::Replaced;
+        type Output = Nested<List, <Tail as UpdateAt<N, P, T>>::Output>;
+
+        fn update_at(self, value: T) -> (Self::Replaced, Self::Output) {
+            let (replaced, tail) = self.1.update_at(value);
+            (replaced, Nested(self.0, tail))
+        }
+    }
+
+    pub trait SwapStep<L, N, M> {
+        type Output;
+
+        fn swap_step(list: L) -> Self::Output;
+    }
+
+    impl<L, N, M> SwapStep<L, N, M> for Less
+    where
+        L: SwapOrdered<N, M>,
+    {
+        type Output = <L as SwapOrdered<N, M>>::Output;
+
+        fn swap_step(list: L) -> Self::Output {
+            list.swap_ordered()
+        }
+    }
+
+    impl<L, N, M> SwapStep<L, N, M> for Equal
+    where
+        L: Get<N>,
+    {
+        type Output = L;
+
+        fn swap_step(list: L) -> Self::Output {
+            list
+        }
+    }
+
+    impl<L, N, M> SwapStep<L, N, M> for Greater
+    where
+        L: SwapOrdered<M, N>,
+    {
+        type Output = <L as SwapOrdered<M, N>>::Output;
+
+        fn swap_step(list: L) -> Self::Output {
+            list.swap_ordered()
+        }
+    }
+
+    // Swaps the elements at `N` and `M`, where `N` is less than `M`
+    pub trait SwapOrdered<N, M> {
+        type Output;
+
+        fn swap_ordered(self) -> Self::Output;
+    }
+
+    impl<L, N, M> SwapOrdered<N, M> for L
+    where
+        L: Remove<M>,
+        <L as Remove<M>>::Output: Replace<N, <L as Remove<M>>::Removed>,
+        <<L as Remove<M>>::Output as Replace<N, <L as Remove<M>>::Removed>>::Output: Insert<M, <<L as Remove<M>>::Output as Replace<N, <L as Remove<M>>::Removed>>::Replaced>,
+    {
+        type Output =
+            <<<L as Remove<M>>::Output as Replace<N, <L as Remove<M>>::Removed>>::Output as Insert<
+                M,
+                <<L as Remove<M>>::Output as Replace<N, <L as Remove<M>>::Removed>>::Replaced,
+            >>::Output;
+
+        fn swap_ordered(self) -> Self::Output {
+            let (last, rest) = Remove::remove(self);
+            let (first, rest) = Replace::replace(rest, last);
+            Insert::insert(rest, first)
+        }
+    }
+
+    pub trait ReverseOnto<Acc> {
+        type Output;
+
+        fn reverse_onto(self, acc: Acc) -> Self::Output;
+    }
+
+    impl<Acc> ReverseOnto<Acc> for Nil {
+        type Output = Acc;
+
+        fn reverse_onto(self, acc: Acc) -> Self::Output {
+            acc
+        }
+    }
+
+    impl<Head, Tail, Acc> ReverseOnto<Acc> for Cons<Head, Tail>
+    where
+        Tail: ReverseOnto<Cons<Head, Acc>>,
+    {
+        type Output = <Tail as ReverseOnto<Cons<Head, Acc>>>::Output;
+
+        fn reverse_onto(self, acc: Acc) -> Self::Output {
+            self.1.reverse_onto(Cons(self.0, acc))
+        }
+    }
+
+    impl<List, Tail, Acc> ReverseOnto<Acc> for Nested<List, Tail>
+    where
+        Tail: ReverseOnto<Nested<List, Acc>>,
+    {
+        type Output = <Tail as ReverseOnto<Nested<List, Acc>>>::Output;
+
+        fn reverse_onto(self, acc: Acc) -> Self::Output {
+            self.1.reverse_onto(Nested(self.0, acc))
+        }
+    }
+
+    pub trait XorSome<T> {
+        type Output: Optional<T>;
+
+        fn xor_some(self, value: T) -> Self::Output;
+    }
+
+    impl<T> XorSome<T> for TNone {
+        type Output = TSome<T>;
+
+        fn xor_some(self, value: T) -> Self::Output {
+            TSome(value)
+        }
+    }
+
+    impl<T> XorSome<T> for TSome<T> {
+        type Output = TNone;
+
+        fn xor_some(self, _: T) -> Self::Output {
+            TNone
+        }
+    }
+
+    pub trait SignedSub<N: Nat, M: Nat> {
+        type Output;
+
+        fn signed_sub() -> Self::Output;
+    }
+
+    impl<N: Nat, M: Nat> SignedSub<N, M> for Less
+    where
+        M: Sub<N>,
+    {
+        type Output = NegInt<<M as Sub<N>>::Output>;
+
+        fn signed_sub() -> Self::Output {
+            NegInt(PhantomData)
+        }
+    }
+
+    impl<N: Nat, M: Nat> SignedSub<N, M> for Equal {
+        type Output = ZeroInt;
+
+        fn signed_sub() -> Self::Output {
+            ZeroInt
+        }
+    }
+
+    impl<N: Nat, M: Nat> SignedSub<N, M> for Greater
+    where
+        N: Sub<M>,
+    {
+        type Output = PosInt<<N as Sub<M>>::Output>;
+
+        fn signed_sub() -> Self::Output {
+            PosInt(PhantomData)
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use tlist::{HList, List, Nil, assert_type_eq, list};
+
+#[test]
+fn append() {
+    let list = list![1, "a"].append(list![2.0]);
+
+    assert_eq!(list, list![1, "a", 2.0]);
+    assert_eq!(Nil.append(list![1]), list![1]);
+    assert_eq!(list![1].append(Nil), list![1]);
+    assert_type_eq!(
+        <List![i32, u8] as tlist::Append<List![()]>>::Output,
+        List![i32, u8, ()]
+    );
+}
+
+#[test]
+fn add() {
+    assert_eq!(
+        list![1, "a"] + list![2.0] + list!['b'],
+        list![1, "a", 2.0, 'b']
+    );
+    assert_eq!(Nil + list![1], list![1]);
+}
+
+#[test]
+fn concat() {
+    let lists = list![list![1, 2], Nil, list!["a"]];
+
+    assert_eq!(lists.concat(), list![1, 2, "a"]);
+    assert_eq!(Nil.concat(), Nil);
+}
+
+#[test]
+fn spliced_tail() {
+    type Tail = List![u8, ()];
+
+    assert_type_eq!(List![i32, ..Tail], List![i32, u8, ()]);
+    assert_type_eq!(List![i32, f64, ..List![u8]], List![i32, f64, u8]);
+    assert_type_eq!(List![..Tail], Tail);
+    assert_type_eq!(List![i32, u8,], List![i32, u8]);
+}
@@ -0,0 +1,22 @@
+use tlist::{
+    All, List, Nat, Succ, Zero, assert_impl, assert_len, assert_nat_eq, assert_type_eq, bin_nat,
+    expr, nat,
+};
+
+type One = Succ<Zero>;
+type Two = Succ<One>;
+
+assert_nat_eq!(expr!(+ One, Two), nat!(3));
+assert_nat_eq!(bin_nat!(6), expr!(Two * 3));
+assert_type_eq!(expr!(One + One), Two);
+assert_len!(List![i32, u8], 2);
+assert_len!(List![], 0);
+assert_impl!(List![i32, i32]: All<i32>);
+assert_impl!(Two: Nat + Copy);
+
+#[test]
+fn assert_in_fn() {
+    assert_nat_eq!(nat!(0), Zero);
+    assert_type_eq!(List![u8], tlist::Cons<u8, tlist::Nil>);
+    assert_len!(List![(), (), ()], 1 + 2);
+}
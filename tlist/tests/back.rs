@@ -0,0 +1,24 @@
+use tlist::{HList, List, Nil, assert_type_eq, list};
+
+#[test]
+fn push_back() {
+    assert_eq!(Nil.push_back(1), list![1]);
+    assert_eq!(list![1].push_back("a").push_back(2.0), list![1, "a", 2.0]);
+}
+
+#[test]
+fn pop_back() {
+    assert_eq!(list![1, "a", 2.0].pop_back(), (2.0, list![1, "a"]));
+    assert_eq!(list![1].pop_back(), (1, Nil));
+    assert_type_eq!(<List![i32, u8] as tlist::Last>::Output, u8);
+    assert_type_eq!(<List![i32, u8] as tlist::Init>::Output, List![i32]);
+}
+
+#[test]
+fn last() {
+    let mut list = list![1, "a", 2.0];
+
+    assert_eq!(*list.last(), 2.0);
+    *list.last_mut() += 1.0;
+    assert_eq!(list, list![1, "a", 3.0]);
+}
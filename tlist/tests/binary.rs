@@ -0,0 +1,46 @@
+use nat_macros::{bin_nat, nat};
+use tlist::{B0, B1, HList, Nat, UTerm, assert_type_eq, list};
+
+#[test]
+fn bin_nat_value() {
+    assert_type_eq!(bin_nat!(0), UTerm);
+    assert_type_eq!(bin_nat!(6), B0<B1<B1<UTerm>>>);
+    assert_eq!(<bin_nat!(5)>::VALUE, 5);
+    assert_eq!(<bin_nat!(1000)>::VALUE, 1000);
+}
+
+#[test]
+fn bin_arithmetic() {
+    assert_type_eq!(<bin_nat!(3) as Nat>::Add<bin_nat!(5)>, bin_nat!(8));
+    assert_type_eq!(<bin_nat!(0) as Nat>::Add<bin_nat!(5)>, bin_nat!(5));
+    assert_type_eq!(<bin_nat!(6) as Nat>::Mul<bin_nat!(7)>, bin_nat!(42));
+    assert_type_eq!(<bin_nat!(6) as Nat>::Mul<bin_nat!(0)>, bin_nat!(0));
+    assert_eq!(
+        <bin_nat!(1000) as Nat>::Mul::<bin_nat!(1000)>::VALUE,
+        1_000_000
+    );
+    assert_eq!(<bin_nat!(1024) as Nat>::Add::<nat!(3)>::VALUE, 1027);
+}
+
+#[test]
+fn mixed_arithmetic() {
+    assert_type_eq!(<nat!(1) as Nat>::Add<bin_nat!(2)>, nat!(3));
+    assert_type_eq!(<nat!(0) as Nat>::Add<bin_nat!(2)>, nat!(2));
+    assert_type_eq!(<nat!(2) as Nat>::Mul<bin_nat!(3)>, nat!(6));
+    assert_type_eq!(<bin_nat!(2) as Nat>::Add<nat!(1)>, bin_nat!(3));
+
+    let list = list![1, "two", 3.0, '4'];
+    assert_eq!(list.get::<<nat!(1) as Nat>::Add<bin_nat!(2)>>(), &'4');
+}
+
+#[test]
+fn conversion() {
+    assert_type_eq!(<bin_nat!(5) as Nat>::Peano, nat!(5));
+    assert_type_eq!(<bin_nat!(0) as Nat>::Peano, nat!(0));
+    assert_type_eq!(<nat!(5) as Nat>::Binary, bin_nat!(5));
+    assert_type_eq!(<nat!(8) as Nat>::Binary, bin_nat!(8));
+    assert_type_eq!(<bin_nat!(9) as Nat>::Binary, bin_nat!(9));
+
+    let list = list![1, "two", 3.0];
+    assert_eq!(list.get::<<bin_nat!(1) as Nat>::Peano>(), &"two");
+}
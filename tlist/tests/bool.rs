@@ -0,0 +1,39 @@
+use tlist::{If, TBool, TFalse, TTrue, assert_type_eq};
+
+#[test]
+fn value() {
+    const { assert!(TTrue::VALUE) };
+    const { assert!(!TFalse::VALUE) };
+}
+
+#[test]
+fn logic() {
+    assert_type_eq!(<TTrue as TBool>::Not, TFalse);
+    assert_type_eq!(<TFalse as TBool>::Not, TTrue);
+
+    assert_type_eq!(<TFalse as TBool>::Or<TTrue>, TTrue);
+    assert_type_eq!(<TFalse as TBool>::Or<TFalse>, TFalse);
+
+    assert_type_eq!(<TTrue as TBool>::And<TTrue>, TTrue);
+    assert_type_eq!(<TTrue as TBool>::And<TFalse>, TFalse);
+    assert_type_eq!(<TFalse as TBool>::And<TTrue>, TFalse);
+
+    assert_type_eq!(<TTrue as TBool>::Xor<TTrue>, TFalse);
+    assert_type_eq!(<TTrue as TBool>::Xor<TFalse>, TTrue);
+    assert_type_eq!(<TFalse as TBool>::Xor<TTrue>, TTrue);
+
+    assert_type_eq!(<TTrue as TBool>::Implies<TFalse>, TFalse);
+    assert_type_eq!(<TFalse as TBool>::Implies<TFalse>, TTrue);
+}
+
+#[test]
+fn select() {
+    assert_type_eq!(If<TTrue, u8, i32>, u8);
+    assert_type_eq!(If<TFalse, u8, i32>, i32);
+
+    let then: u8 = TTrue::select(1, "no");
+    let otherwise: &str = TFalse::select(1, "yes");
+
+    assert_eq!(then, 1);
+    assert_eq!(otherwise, "yes");
+}
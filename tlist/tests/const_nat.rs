@@ -0,0 +1,37 @@
+use nat_macros::{bin_nat, nat};
+use tlist::{ConstNat, Get, HList, Nat, ToArray, ToNat, assert_type_eq, list};
+
+type Index<const N: usize> = <<ConstNat<N> as ToNat>::Output as Nat>::Peano;
+
+fn nth<const N: usize, L>(list: &L) -> &<L as Get<Index<N>>>::Output
+where
+    ConstNat<N>: ToNat,
+    L: HList + Get<Index<N>>,
+{
+    HList::get::<Index<N>>(list)
+}
+
+#[test]
+fn const_to_nat() {
+    assert_type_eq!(<ConstNat<0> as ToNat>::Output, bin_nat!(0));
+    assert_type_eq!(<ConstNat<5> as ToNat>::Output, bin_nat!(5));
+    assert_type_eq!(<ConstNat<1024> as ToNat>::Output, bin_nat!(1024));
+    assert_type_eq!(Index<5>, nat!(5));
+    assert_eq!(<<ConstNat<1000> as ToNat>::Output as Nat>::VALUE, 1000);
+
+    let list = list![1, "two", 3.0];
+    assert_eq!(nth::<1, _>(&list), &"two");
+    assert_eq!(nth::<2, _>(&list), &3.0);
+}
+
+#[test]
+fn nat_to_array() {
+    let empty: <nat!(0) as ToArray>::Array<u8> = [];
+    let peano: <nat!(3) as ToArray>::Array<u8> = [1, 2, 3];
+    type Large = bin_nat!(1024);
+    let binary: <Large as ToArray>::Array<u8> = [0; 1024];
+
+    assert_eq!(empty.len(), 0);
+    assert_eq!(peano.len(), 3);
+    assert_eq!(binary.len(), 1024);
+}
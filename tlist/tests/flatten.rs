@@ -0,0 +1,130 @@
+use std::rc::Rc;
+
+use tlist::{HList, List, Nested, Nil, TSome, assert_type_eq, list, nat};
+
+#[derive(Debug, PartialEq)]
+struct Leaf;
+
+#[test]
+fn flatten() {
+    let inner = list!["b"].push_nested(list!['a']);
+    let nested = list![2.0, Leaf]
+        .push_nested(Nil)
+        .push_nested(list![1].push_nested(inner));
+
+    assert_eq!(nested.flatten(), list!['a', "b", 1, 2.0, Leaf]);
+    assert_eq!(Nil.flatten(), Nil);
+    assert_type_eq!(
+        <Nested<List![i32, u8], List![char]> as tlist::Flatten>::Output,
+        List![i32, u8, char]
+    );
+}
+
+#[test]
+fn plain_elements() {
+    let nested = list![(3, 4), [5u8; 2], Rc::new(6), list![7]].push_nested(list![1, 2]);
+
+    assert_eq!(
+        nested.flatten(),
+        list![1, 2, (3, 4), [5u8; 2], Rc::new(6), list![7]]
+    );
+}
+
+#[test]
+fn flatten_once() {
+    let nested = list![2.0, Nil].push_nested(list![1].push_nested(list!['a']));
+
+    assert_eq!(
+        nested.flatten_once(),
+        list![1].push_nested(list!['a']).append(list![2.0, Nil])
+    );
+    assert_eq!(Nil.flatten_once(), Nil);
+}
+
+#[test]
+fn nest() {
+    let built = list![list!['a', "b"], 1];
+
+    assert_eq!(
+        built.nest::<nat!(0)>(),
+        list![1].push_nested(list!['a', "b"])
+    );
+    assert_eq!(built.nest::<nat!(0)>().flatten(), list!['a', "b", 1]);
+    assert_eq!(
+        list![(), list![list![1], 2], list![3]]
+            .nest::<nat!(1)>()
+            .nest::<nat!(2)>()
+            .flatten(),
+        list![(), list![1], 2, 3]
+    );
+    assert_type_eq!(
+        <List![List![i32, u8], char] as tlist::Nest<nat!(0)>>::Output,
+        Nested<List![i32, u8], List![char]>
+    );
+}
+
+#[test]
+fn nested_access() {
+    let mut nested = list![2.0, 'c'].push_nested(list![1, 2]);
+
+    assert_eq!(nested.len(), 3);
+    assert_eq!(*nested.get::<nat!(0)>(), list![1, 2]);
+    *nested.get_mut::<nat!(0)>().get_mut::<nat!(1)>() = 3;
+    assert_eq!(*nested.get_checked::<nat!(0)>(), list![1, 3]);
+    assert_eq!(nested.get_optional::<nat!(1)>(), TSome(&2.0));
+    assert_eq!(*nested.last(), 'c');
+    assert_eq!(nested.get_path::<List![nat!(0), nat!(1)]>(), &3);
+
+    let nested = Nil.push_nested(list![1]).push_nested(list![2]);
+    assert_eq!(nested.iter().collect::<Vec<_>>(), [&list![2], &list![1]]);
+    assert_eq!(nested.into_iter().count(), 2);
+}
+
+#[test]
+fn nested_update() {
+    let nested = list![2.0, 'c'].push_nested(list![1, 2]);
+
+    assert_eq!(
+        nested.insert::<nat!(1), _>("s").flatten(),
+        list![1, 2, "s", 2.0, 'c']
+    );
+    assert_eq!(nested.pop(), (list![1, 2], list![2.0, 'c']));
+    assert_eq!(
+        nested.replace::<nat!(0), _>(0),
+        (list![1, 2], list![0, 2.0, 'c'])
+    );
+    assert_eq!(
+        nested.replace::<nat!(2), _>('d').1.flatten(),
+        list![1, 2, 2.0, 'd']
+    );
+    assert_eq!(
+        nested.remove_optional::<nat!(1)>(),
+        (TSome(2.0), list!['c'].push_nested(list![1, 2]))
+    );
+    assert_eq!(
+        nested.split_at::<nat!(1)>(),
+        (Nil.push_nested(list![1, 2]), list![2.0, 'c'])
+    );
+    assert_eq!(
+        nested.pop_back(),
+        ('c', list![2.0].push_nested(list![1, 2]))
+    );
+    assert_eq!(
+        Nil.push_nested(list![1])
+            .push_nested(list![2])
+            .push(0)
+            .pop_back(),
+        (list![1], Nil.push_nested(list![2]).push(0))
+    );
+    assert_eq!(
+        list![0].zip(Nil.push_nested(list![1])),
+        list![(0, list![1])]
+    );
+    assert_eq!((nested + list![4]).flatten(), list![1, 2, 2.0, 'c', 4]);
+    assert_eq!(nested.reverse().flatten(), list!['c', 2.0, 1, 2]);
+    assert_eq!(
+        nested.zip(list!['x', 'y', 'z']),
+        list![(list![1, 2], 'x'), (2.0, 'y'), ('c', 'z')]
+    );
+    assert_eq!(nested.take_ref::<nat!(2)>(), list![&list![1, 2], &2.0]);
+}
@@ -0,0 +1,53 @@
+use tlist::{HList, List, Nat, OutOfBounds, TNone, TSome, assert_type_eq, list, nat};
+
+#[test]
+fn get_checked() {
+    let mut list = list![1, "a", 2.0];
+
+    assert_eq!(*list.get_checked::<nat!(0)>(), 1);
+    assert_eq!(*list.get_checked::<nat!(1)>(), "a");
+    assert_eq!(*list.get_checked::<nat!(2)>(), 2.0);
+
+    *list.get_mut::<nat!(0)>() = 3;
+    assert_eq!(*list.get_checked::<nat!(0)>(), 3);
+}
+
+#[test]
+fn get_optional_out_of_bounds() {
+    type L = List![i32, ()];
+
+    assert_type_eq!(<L as tlist::GetOptional<nat!(1)>>::Output, ());
+    assert_type_eq!(<L as tlist::GetOptional<nat!(1)>>::Optional, TSome<()>);
+    assert_type_eq!(
+        <L as tlist::GetOptional<nat!(3)>>::Output,
+        OutOfBounds<nat!(3)>
+    );
+    assert_type_eq!(<L as tlist::GetOptional<nat!(3)>>::Optional, TNone);
+
+    let list = list![1, ()];
+    assert_eq!(list.get_optional::<nat!(1)>(), TSome(&()));
+    assert_eq!(list.get_optional::<nat!(5)>(), TNone);
+}
+
+#[test]
+fn remove_optional_out_of_bounds() {
+    type L = List![i32, ()];
+
+    assert_type_eq!(
+        <L as tlist::RemoveOptional<nat!(2)>>::Removed,
+        OutOfBounds<nat!(2)>
+    );
+
+    let (removed, rest) = list![1, ()].remove_optional::<nat!(4)>();
+    assert_eq!(removed, TNone);
+    assert_eq!(rest, list![1, ()]);
+
+    fn index<I: Nat>(_: OutOfBounds<I>) -> usize {
+        I::VALUE
+    }
+
+    assert_eq!(
+        index(<L as tlist::GetOptional<nat!(7)>>::Output::default()),
+        7
+    );
+}
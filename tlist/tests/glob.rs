@@ -0,0 +1,18 @@
+#![allow(unused_imports)]
+
+use tlist::*;
+
+struct Guard<'a>(&'a mut bool);
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        *self.0 = true;
+    }
+}
+
+#[test]
+fn prelude_drop_is_not_shadowed() {
+    let mut dropped = false;
+    drop(Guard(&mut dropped));
+    assert!(dropped);
+}
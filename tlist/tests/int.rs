@@ -0,0 +1,60 @@
+use nat_macros::nat;
+use tlist::{Int, NegInt, PosInt, ToNat, ZeroInt, assert_type_eq};
+
+type Plus<N> = PosInt<N>;
+type Minus<N> = NegInt<N>;
+
+#[test]
+fn value() {
+    assert_eq!(ZeroInt::VALUE, 0);
+    assert_eq!(Plus::<nat!(3)>::VALUE, 3);
+    assert_eq!(Minus::<nat!(3)>::VALUE, -3);
+    assert_eq!(<Minus<nat!(2)> as Int>::Abs::VALUE, 2);
+}
+
+#[test]
+fn add_sub() {
+    assert_type_eq!(
+        <Plus<nat!(2)> as std::ops::Add<Plus<nat!(3)>>>::Output,
+        Plus<nat!(5)>,
+    );
+    assert_type_eq!(
+        <Plus<nat!(2)> as std::ops::Add<Minus<nat!(3)>>>::Output,
+        Minus<nat!(1)>,
+    );
+    assert_type_eq!(
+        <Minus<nat!(2)> as std::ops::Add<Plus<nat!(2)>>>::Output,
+        ZeroInt,
+    );
+    assert_type_eq!(
+        <Minus<nat!(2)> as std::ops::Sub<Minus<nat!(5)>>>::Output,
+        Plus<nat!(3)>,
+    );
+    assert_type_eq!(
+        <ZeroInt as std::ops::Sub<Plus<nat!(1)>>>::Output,
+        Minus<nat!(1)>,
+    );
+
+    let value = Plus::<nat!(1)>::default() - Plus::<nat!(4)>::default() + ZeroInt;
+    assert_eq!(value, Minus::<nat!(3)>::default());
+}
+
+#[test]
+fn mul_neg() {
+    assert_type_eq!(
+        <Minus<nat!(2)> as std::ops::Mul<Minus<nat!(3)>>>::Output,
+        Plus<nat!(6)>,
+    );
+    assert_type_eq!(
+        <Plus<nat!(2)> as std::ops::Mul<Minus<nat!(3)>>>::Output,
+        Minus<nat!(6)>,
+    );
+    assert_type_eq!(<ZeroInt as std::ops::Mul<Minus<nat!(3)>>>::Output, ZeroInt,);
+    assert_type_eq!(<Plus<nat!(4)> as std::ops::Neg>::Output, Minus<nat!(4)>,);
+}
+
+#[test]
+fn to_nat() {
+    assert_type_eq!(<Plus<nat!(4)> as ToNat>::Output, nat!(4));
+    assert_type_eq!(<ZeroInt as ToNat>::Output, nat!(0));
+}
@@ -0,0 +1,31 @@
+use tlist::{HList, Len, List, Nat, Nil, ToArray, assert_type_eq, list, nat};
+
+#[test]
+fn len_type() {
+    assert_type_eq!(<Nil as Len>::Len, nat!(0));
+    assert_type_eq!(<List![i32, u8, ()] as Len>::Len, nat!(3));
+    assert_eq!(<List![i32, u8] as Len>::LEN, 2);
+}
+
+#[test]
+fn len_value() {
+    let list = list![1, "a", 2.0];
+
+    assert_eq!(list.len(), 3);
+    assert!(!list.is_empty());
+    assert_eq!(Nil.len(), 0);
+    assert!(Nil.is_empty());
+}
+
+#[test]
+fn array_from_len() {
+    fn zeros<L: Len>(_: &L) -> <<L::Len as Nat>::Binary as ToArray>::Array<u8>
+    where
+        <L::Len as Nat>::Binary: ToArray,
+        <<L::Len as Nat>::Binary as ToArray>::Array<u8>: Default,
+    {
+        Default::default()
+    }
+
+    assert_eq!(zeros(&list![1, "a", 2.0]), [0u8; 3]);
+}
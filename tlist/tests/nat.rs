@@ -0,0 +1,108 @@
+use nat_macros::nat;
+use tlist::{
+    CheckedSub, Cmp, Div, DivRem, Equal, Gcd, Greater, IsLess, IsLessOrEqual, Lcm, Less, Log2, Max,
+    Min, Nat, Pow, Rem, SaturatingSub, Sqrt, Sub, TNone, TOrdering, TSome, assert_type_eq,
+};
+
+#[test]
+fn nat_macro() {
+    assert_eq!(<nat!(2 * 8 + 1)>::VALUE, 17);
+    assert_eq!(<nat!((10 - 4) / 2 % 2)>::VALUE, 1);
+    assert_eq!(<nat!(3usize)>::VALUE, 3);
+    assert_eq!(<nat!(7u8)>::VALUE, 7);
+    assert_type_eq!(tlist::nat!(4), nat!(2 + 2));
+    assert_type_eq!(tlist::bin_nat!(4 * 4), tlist::bin_nat!(16));
+}
+
+#[test]
+fn sub() {
+    assert_eq!(<nat!(5) as Sub<nat!(3)>>::Output::VALUE, 2);
+    assert_eq!(<nat!(3) as Sub<nat!(3)>>::Output::VALUE, 0);
+    assert_eq!(<nat!(4) as Sub<nat!(0)>>::Output::VALUE, 4);
+}
+
+#[test]
+fn checked_sub() {
+    assert_type_eq!(<nat!(5) as CheckedSub<nat!(2)>>::Output, TSome<nat!(3)>,);
+    assert_type_eq!(<nat!(2) as CheckedSub<nat!(2)>>::Output, TSome<nat!(0)>,);
+    assert_type_eq!(<nat!(2) as CheckedSub<nat!(5)>>::Output, TNone);
+}
+
+#[test]
+fn saturating_sub() {
+    assert_eq!(<nat!(5) as SaturatingSub<nat!(2)>>::Output::VALUE, 3);
+    assert_eq!(<nat!(2) as SaturatingSub<nat!(5)>>::Output::VALUE, 0);
+}
+
+#[test]
+fn div_rem() {
+    assert_eq!(<nat!(7) as DivRem<nat!(2)>>::Quotient::VALUE, 3);
+    assert_eq!(<nat!(7) as DivRem<nat!(2)>>::Remainder::VALUE, 1);
+    assert_eq!(<nat!(0) as Div<nat!(3)>>::Output::VALUE, 0);
+    assert_eq!(<nat!(6) as Div<nat!(3)>>::Output::VALUE, 2);
+    assert_eq!(<nat!(2) as Rem<nat!(5)>>::Output::VALUE, 2);
+}
+
+#[test]
+fn gcd_lcm() {
+    assert_eq!(<nat!(12) as Gcd<nat!(8)>>::Output::VALUE, 4);
+    assert_eq!(<nat!(0) as Gcd<nat!(5)>>::Output::VALUE, 5);
+    assert_eq!(<nat!(5) as Gcd<nat!(0)>>::Output::VALUE, 5);
+    assert_eq!(<nat!(4) as Lcm<nat!(6)>>::Output::VALUE, 12);
+    assert_eq!(<nat!(0) as Lcm<nat!(6)>>::Output::VALUE, 0);
+    assert_eq!(<nat!(6) as Lcm<nat!(0)>>::Output::VALUE, 0);
+}
+
+#[test]
+fn cmp() {
+    assert_type_eq!(<nat!(2) as Cmp<nat!(5)>>::Output, Less);
+    assert_type_eq!(<nat!(3) as Cmp<nat!(3)>>::Output, Equal);
+    assert_type_eq!(<nat!(4) as Cmp<nat!(1)>>::Output, Greater);
+    assert_eq!(
+        <<nat!(0) as Cmp<nat!(1)>>::Output as TOrdering>::VALUE,
+        std::cmp::Ordering::Less
+    );
+}
+
+#[test]
+fn min_max() {
+    assert_eq!(<nat!(2) as Min<nat!(5)>>::Output::VALUE, 2);
+    assert_eq!(<nat!(5) as Min<nat!(2)>>::Output::VALUE, 2);
+    assert_eq!(<nat!(2) as Max<nat!(5)>>::Output::VALUE, 5);
+    assert_eq!(<nat!(5) as Max<nat!(2)>>::Output::VALUE, 5);
+}
+
+#[test]
+fn is_less() {
+    fn less<N: IsLess<M>, M: Nat>() {}
+    fn less_or_equal<N: IsLessOrEqual<M>, M: Nat>() {}
+
+    less::<nat!(1), nat!(2)>();
+    less_or_equal::<nat!(2), nat!(2)>();
+    less_or_equal::<nat!(0), nat!(0)>();
+}
+
+#[test]
+fn pow() {
+    assert_eq!(<nat!(2) as Pow<nat!(3)>>::Output::VALUE, 8);
+    assert_eq!(<nat!(3) as Pow<nat!(0)>>::Output::VALUE, 1);
+    assert_eq!(<nat!(0) as Pow<nat!(0)>>::Output::VALUE, 1);
+    assert_eq!(<nat!(0) as Pow<nat!(2)>>::Output::VALUE, 0);
+}
+
+#[test]
+fn log2() {
+    assert_eq!(<nat!(1) as Log2>::Output::VALUE, 0);
+    assert_eq!(<nat!(2) as Log2>::Output::VALUE, 1);
+    assert_eq!(<nat!(7) as Log2>::Output::VALUE, 2);
+    assert_eq!(<nat!(8) as Log2>::Output::VALUE, 3);
+}
+
+#[test]
+fn sqrt() {
+    assert_eq!(<nat!(0) as Sqrt>::Output::VALUE, 0);
+    assert_eq!(<nat!(1) as Sqrt>::Output::VALUE, 1);
+    assert_eq!(<nat!(8) as Sqrt>::Output::VALUE, 2);
+    assert_eq!(<nat!(9) as Sqrt>::Output::VALUE, 3);
+    assert_eq!(<nat!(15) as Sqrt>::Output::VALUE, 3);
+}
@@ -0,0 +1,95 @@
+use tlist::{Optional, TFalse, TNone, TSome, TTrue, assert_type_eq};
+
+#[test]
+fn constructors() {
+    let some = TSome::new(3);
+
+    assert_eq!(some, TSome(3));
+    assert_eq!(some.unwrap(), 3);
+    assert_eq!(TSome(4).0, 4);
+}
+
+#[test]
+fn is_some() {
+    const { assert!(<TSome<i32> as Optional<i32>>::IS_SOME) };
+    const { assert!(!<TNone as Optional<i32>>::IS_SOME) };
+}
+
+#[test]
+fn as_ref_as_mut() {
+    let mut some = TSome(String::from("a"));
+
+    assert_eq!(some.as_ref().unwrap(), "a");
+    some.as_mut().unwrap().push('b');
+    assert_eq!(some.unwrap(), "ab");
+
+    let mut none = TNone;
+    assert_eq!(Optional::<i32>::as_ref(&none), TNone);
+    assert_eq!(Optional::<i32>::as_mut(&mut none), TNone);
+}
+
+#[test]
+fn map() {
+    let mapped = TSome(2).map(|n| n.to_string());
+    assert_eq!(mapped.unwrap(), "2");
+
+    let mapped = Optional::<i32>::map(TNone, |n| n.to_string());
+    assert_eq!(mapped, TNone);
+}
+
+#[test]
+fn and_then() {
+    assert_eq!(TSome(2).and_then(|n| TSome(n * 2)), TSome(4));
+    assert_eq!(TSome(2).and_then::<i32, _, _>(|_| TNone), TNone);
+    assert_eq!(Optional::<i32>::and_then(TNone, |n| TSome(n * 2)), TNone);
+}
+
+#[test]
+fn or() {
+    assert_eq!(TSome(1).or(TSome(2)), TSome(1));
+    assert_eq!(TNone.or(TSome(2)), TSome(2));
+    assert_type_eq!(<TNone as Optional<i32>>::Or<TNone>, TNone);
+}
+
+#[test]
+fn unwrap_or() {
+    assert_eq!(TSome(1).unwrap_or(2), 1);
+    assert_eq!(TNone.unwrap_or(2), 2);
+}
+
+#[test]
+fn is_some_type() {
+    assert_type_eq!(<TSome<i32> as Optional<i32>>::IsSome, TTrue);
+    assert_type_eq!(<TNone as Optional<i32>>::IsSome, TFalse);
+}
+
+#[test]
+fn and() {
+    assert_eq!(TSome(1).and(TSome("a")), TSome("a"));
+    assert_eq!(TSome(1).and::<&str, _>(TNone), TNone);
+    assert_eq!(Optional::<i32>::and(TNone, TSome("a")), TNone);
+    assert_type_eq!(<TSome<i32> as Optional<i32>>::And<u8, TSome<u8>>, TSome<u8>);
+}
+
+#[test]
+fn xor() {
+    assert_eq!(TSome(1).xor(TSome(2)), TNone);
+    assert_eq!(TSome(1).xor(TNone), TSome(1));
+    assert_eq!(TNone.xor(TSome(2)), TSome(2));
+    assert_eq!(Optional::<i32>::xor(TNone, TNone), TNone);
+    assert_type_eq!(<TSome<i32> as Optional<i32>>::Xor<TSome<i32>>, TNone);
+}
+
+#[test]
+fn flatten() {
+    assert_eq!(TSome(TSome(1)).flatten(), TSome(1));
+    assert_eq!(TSome(TNone).flatten::<i32>(), TNone);
+    assert_eq!(Optional::<TSome<i32>>::flatten(TNone), TNone);
+}
+
+#[test]
+fn zip() {
+    assert_eq!(TSome(1).zip(TSome("a")), TSome((1, "a")));
+    assert_eq!(TSome(1).zip::<&str, _>(TNone), TNone);
+    assert_eq!(Optional::<i32>::zip(TNone, TSome("a")), TNone);
+}
@@ -0,0 +1,49 @@
+use tlist::{HList, List, assert_type_eq, list, nat};
+
+#[test]
+fn get_path() {
+    let list = list![1, "a", list![list![2.0, 'b'], 3]];
+
+    assert_eq!(*list.get_path::<List![nat!(2), nat!(0), nat!(1)]>(), 'b');
+    assert_eq!(*list.get_path::<List![nat!(2), nat!(1)]>(), 3);
+    assert_eq!(*list.get_path::<List![nat!(0)]>(), 1);
+    assert_eq!(*list.get_path::<List![]>(), list);
+}
+
+#[test]
+fn get_path_mut() {
+    let mut list = list![1, list![2.0, list!['b']]];
+
+    *list.get_path_mut::<List![nat!(1), nat!(1), nat!(0)]>() = 'c';
+    *list.get_path_mut::<List![nat!(1), nat!(0)]>() += 1.0;
+
+    assert_eq!(list, list![1, list![3.0, list!['c']]]);
+}
+
+#[test]
+fn update_path() {
+    let list = list![1, list![2.0, list!['b']]];
+
+    let (old, list) = list.update_path::<List![nat!(1), nat!(1), nat!(0)], _>("c");
+
+    assert_eq!(old, 'b');
+    assert_eq!(list, list![1, list![2.0, list!["c"]]]);
+    assert_type_eq!(
+        <List![i32, List![u8]] as tlist::UpdatePath<List![nat!(1), nat!(0)], ()>>::Output,
+        List![i32, List![()]]
+    );
+}
+
+#[test]
+fn update_path_nested() {
+    let list = list![1].push_nested(list![2.0, 'b']);
+
+    let (old, list) = list.update_path::<List![nat!(0), nat!(1)], _>("c");
+
+    assert_eq!(old, 'b');
+    assert_eq!(list.flatten(), list![2.0, "c", 1]);
+    assert_eq!(
+        list.update_path::<List![nat!(0)], _>(()),
+        (list![2.0, "c"], list![(), 1])
+    );
+}
@@ -11,6 +11,11 @@ fn expr_test() {
     type Five = expr!(+ Two, Three);
     type Six = expr!(+ One, Two, Three);
     type Seven = expr!(+ One, (* Two, Three));
+    type Nothing = expr!(- Two, Two);
+    type Eight = expr!(- (* Three, Three), One);
+    type Quotient = expr!(/ Seven, Two);
+    type Remainder = expr!(% Seven, Two, Two);
+    type Power = expr!(^ Two, Three);
 
     assert_eq!(One::VALUE, 1);
     assert_eq!(Two::VALUE, 2);
@@ -19,6 +24,32 @@ fn expr_test() {
     assert_eq!(Five::VALUE, 5);
     assert_eq!(Six::VALUE, 6);
     assert_eq!(Seven::VALUE, 7);
+    assert_eq!(Nothing::VALUE, 0);
+    assert_eq!(Eight::VALUE, 8);
+    assert_eq!(Quotient::VALUE, 3);
+    assert_eq!(Remainder::VALUE, 1);
+    assert_eq!(Power::VALUE, 8);
+}
+
+#[allow(unused)]
+#[test]
+fn infix_expr_test() {
+    type One = Succ<Zero>;
+    type Two = Succ<One>;
+    type N = nat!(4);
+    type Seventeen = expr!(One + Two * (N + 3) + 2);
+    type Difference = expr!(N - One - Two);
+    type Quotient = expr!(20 / N / 2);
+    type Remainder = expr!(N * 3 % 5);
+    type Power = expr!(Two ^ 3 ^ 1 * 2);
+    type Mixed = expr!(+ One + Two, (* Two, 3));
+
+    assert_eq!(Seventeen::VALUE, 17);
+    assert_eq!(Difference::VALUE, 1);
+    assert_eq!(Quotient::VALUE, 2);
+    assert_eq!(Remainder::VALUE, 2);
+    assert_eq!(Power::VALUE, 16);
+    assert_eq!(Mixed::VALUE, 9);
 }
 
 #[allow(unused)]
@@ -0,0 +1,24 @@
+use tlist::{HList, List, assert_type_eq, list, nat};
+
+#[test]
+fn replace() {
+    let (old, list) = list![1, "a", 2.0].replace::<nat!(1), _>('b');
+
+    assert_eq!(old, "a");
+    assert_eq!(list, list![1, 'b', 2.0]);
+    assert_type_eq!(
+        <List![i32, u8] as tlist::Replace<nat!(0), ()>>::Output,
+        List![(), u8]
+    );
+}
+
+#[test]
+fn map_at() {
+    let list = list![1, "a", 2.0].map_at::<nat!(0), _>(|n| n.to_string());
+
+    assert_eq!(list, list![String::from("1"), "a", 2.0]);
+    assert_eq!(
+        list.map_at::<nat!(2), _>(|x| x > 1.0),
+        list![String::from("1"), "a", true]
+    );
+}
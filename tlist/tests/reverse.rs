@@ -0,0 +1,23 @@
+use tlist::{AsMutList, AsRefList, HList, List, Nil, assert_type_eq, list};
+
+#[test]
+fn reverse() {
+    assert_eq!(list![1, "a", 2.0].reverse(), list![2.0, "a", 1]);
+    assert_eq!(list![1].reverse(), list![1]);
+    assert_eq!(Nil.reverse(), Nil);
+    assert_type_eq!(
+        <List![i32, u8, ()] as tlist::Reverse>::Output,
+        List![(), u8, i32]
+    );
+}
+
+#[test]
+fn reverse_references() {
+    let mut list = list![1, "a"];
+
+    assert_eq!(list.as_ref_list().reverse(), list![&"a", &1]);
+
+    let reversed = list.as_mut_list().reverse();
+    *reversed.1.0 += 1;
+    assert_eq!(list, list![2, "a"]);
+}
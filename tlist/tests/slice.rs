@@ -0,0 +1,24 @@
+use tlist::{HList, Nil, list, nat};
+
+#[test]
+fn slice() {
+    let list = list![1, "a", 2.0, 'b'];
+
+    assert_eq!(list.slice::<nat!(1), nat!(3)>(), list![&"a", &2.0]);
+    assert_eq!(
+        list.slice::<nat!(0), nat!(4)>(),
+        list![&1, &"a", &2.0, &'b']
+    );
+    assert_eq!(list.slice::<nat!(2), nat!(2)>(), Nil);
+}
+
+#[test]
+fn slice_mut() {
+    let mut list = list![1, "a", 2.0, 'b'];
+
+    let slice = list.slice_mut::<nat!(2), nat!(4)>();
+    *slice.0 += 1.0;
+    *slice.1.0 = 'c';
+
+    assert_eq!(list, list![1, "a", 3.0, 'c']);
+}
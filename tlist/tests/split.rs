@@ -0,0 +1,42 @@
+use tlist::{HList, List, Nil, assert_type_eq, list, nat};
+
+#[test]
+fn split_at() {
+    let (prefix, suffix) = list![1, "a", 2.0].split_at::<nat!(1)>();
+
+    assert_eq!(prefix, list![1]);
+    assert_eq!(suffix, list!["a", 2.0]);
+    assert_eq!(list![1, 2].split_at::<nat!(0)>(), (Nil, list![1, 2]));
+    assert_eq!(list![1, 2].split_at::<nat!(2)>(), (list![1, 2], Nil));
+    assert_type_eq!(
+        <List![i32, u8, ()] as tlist::SplitAt<nat!(2)>>::Suffix,
+        List![()]
+    );
+}
+
+#[test]
+fn take_skip() {
+    assert_eq!(list![1, "a", 2.0].take::<nat!(2)>(), list![1, "a"]);
+    assert_eq!(list![1, "a", 2.0].skip::<nat!(2)>(), list![2.0]);
+    assert_eq!(Nil.take::<nat!(0)>(), Nil);
+}
+
+#[test]
+fn borrowing() {
+    let mut list = list![1, "a", 2.0];
+
+    assert_eq!(
+        list.split_at_ref::<nat!(1)>(),
+        (list![&1], list![&"a", &2.0])
+    );
+    assert_eq!(list.take_ref::<nat!(1)>(), list![&1]);
+    assert_eq!(list.skip_ref::<nat!(2)>(), list![&2.0]);
+
+    *list.take_mut::<nat!(1)>().0 += 1;
+    *list.skip_mut::<nat!(2)>().0 *= 2.0;
+    let (prefix, suffix) = list.split_at_mut::<nat!(2)>();
+    *prefix.1.0 = "b";
+    *suffix.0 += 1.0;
+
+    assert_eq!(list, list![2, "b", 5.0]);
+}
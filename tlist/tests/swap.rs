@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+
+use tlist::{HList, List, Nil, assert_impl, assert_type_eq, list, nat};
+
+#[test]
+fn swap() {
+    let list = list![1, "a", 2.0, 'b'];
+
+    assert_eq!(list.swap::<nat!(0), nat!(2)>(), list![2.0, "a", 1, 'b']);
+    assert_eq!(list.swap::<nat!(3), nat!(1)>(), list![1, 'b', 2.0, "a"]);
+    assert_eq!(list.swap::<nat!(2), nat!(2)>(), list);
+    assert_type_eq!(
+        <List![i32, u8] as tlist::Swap<nat!(0), nat!(1)>>::Output,
+        List![u8, i32]
+    );
+}
+
+#[test]
+fn swap_same_index() {
+    assert_eq!(list![1, "a"].swap::<nat!(1), nat!(1)>(), list![1, "a"]);
+    assert_impl!(List![i32, u8]: tlist::Swap<nat!(1), nat!(1)>);
+
+    // Swapping an index with itself still requires the index to be in range
+    struct Probe<L>(PhantomData<L>);
+
+    trait Fallback {
+        const IN_RANGE: bool = false;
+    }
+
+    impl<L> Fallback for Probe<L> {}
+
+    impl<L: tlist::Swap<nat!(2), nat!(2)>> Probe<L> {
+        #[allow(dead_code)]
+        const IN_RANGE: bool = true;
+    }
+
+    const { assert!(!Probe::<List![i32, u8]>::IN_RANGE) };
+    const { assert!(Probe::<List![i32, u8, ()]>::IN_RANGE) };
+}
+
+#[test]
+fn rotate() {
+    let list = list![1, "a", 2.0];
+
+    assert_eq!(list.rotate_left::<nat!(1)>(), list!["a", 2.0, 1]);
+    assert_eq!(list.rotate_right::<nat!(1)>(), list![2.0, 1, "a"]);
+    assert_eq!(list.rotate_left::<nat!(0)>(), list);
+    assert_eq!(list.rotate_right::<nat!(3)>(), list);
+    assert_eq!(Nil.rotate_right::<nat!(0)>(), Nil);
+}
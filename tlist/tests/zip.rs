@@ -0,0 +1,32 @@
+use tlist::{HList, List, Nil, assert_type_eq, list};
+
+#[test]
+fn zip() {
+    assert_eq!(
+        list![1, "a"].zip(list![2.0, 'b']),
+        list![(1, 2.0), ("a", 'b')]
+    );
+    assert_eq!(Nil.zip(Nil), Nil);
+    assert_type_eq!(
+        <List![i32, u8] as tlist::Zip<List![(), char]>>::Output,
+        List![(i32, ()), (u8, char)]
+    );
+}
+
+#[test]
+fn unzip() {
+    assert_eq!(
+        list![(1, 2.0), ("a", 'b')].unzip(),
+        (list![1, "a"], list![2.0, 'b'])
+    );
+    assert_eq!(Nil.unzip(), (Nil, Nil));
+}
+
+#[test]
+fn zip_ref() {
+    let left = list![1, "a"];
+    let right = list![2.0, 'b'];
+
+    assert_eq!(left.zip_ref(&right), list![(&1, &2.0), (&"a", &'b')]);
+    assert_eq!(left, list![1, "a"]);
+}